@@ -0,0 +1,48 @@
+//! Assets compiled into the binaries so first run works without any loose files
+
+use anyhow::{anyhow, Result};
+use image::RgbaImage;
+
+/// Application icon (same file as favicon.ico in the repo root)
+pub const APP_ICON: &[u8] = include_bytes!("../favicon.ico");
+
+/// Default 100x100 crosshair image
+pub const DEFAULT_CROSSHAIR: &[u8] = include_bytes!("../assets/default_crosshair.png");
+
+/// Image path that refers to the embedded default crosshair instead of a file
+pub const BUILTIN_DEFAULT_CROSSHAIR: &str = "builtin:default";
+
+/// Embedded image bytes for a `builtin:` path, None for regular file paths
+pub fn builtin_image(path: &str) -> Option<&'static [u8]> {
+    match path {
+        BUILTIN_DEFAULT_CROSSHAIR => Some(DEFAULT_CROSSHAIR),
+        _ => None,
+    }
+}
+
+/// Whether the path names an embedded image rather than a file on disk
+pub fn is_builtin(path: &str) -> bool {
+    builtin_image(path).is_some()
+}
+
+/// Decode an embedded image
+pub fn decode(bytes: &[u8]) -> Result<RgbaImage> {
+    image::load_from_memory(bytes)
+        .map(|img| img.to_rgba8())
+        .map_err(|e| anyhow!("Failed to decode embedded image: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_assets_decode() {
+        let bytes = builtin_image(BUILTIN_DEFAULT_CROSSHAIR).unwrap();
+        let crosshair = decode(bytes).unwrap();
+        assert_eq!(crosshair.dimensions(), (100, 100));
+
+        assert!(decode(APP_ICON).is_ok());
+        assert!(!is_builtin("C:\\crosshairs\\dot.png"));
+    }
+}
@@ -0,0 +1,147 @@
+//! Default audio output switching via Windows Core Audio
+//!
+//! Setting the default endpoint uses the undocumented IPolicyConfig interface, the same one
+//! the Sound control panel uses. Devices are matched by friendly name so a profile keeps
+//! working after the device is unplugged and plugged back in.
+
+use anyhow::{anyhow, Result};
+
+/// An active audio output device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioDevice {
+    /// Endpoint ID, e.g. "{0.0.0.00000000}.{guid}"
+    pub id: String,
+    /// Friendly name shown in the Sound settings, e.g. "Headphones (Arctis 7)"
+    pub name: String,
+}
+
+/// Find a device by friendly name (case-insensitive)
+pub fn find_device<'a>(devices: &'a [AudioDevice], name: &str) -> Option<&'a AudioDevice> {
+    devices.iter().find(|d| d.name.eq_ignore_ascii_case(name))
+}
+
+/// Make the named output device the default for all roles
+/// Fails (without changing anything) if the device isn't currently connected
+pub fn set_default_output(name: &str) -> Result<()> {
+    let devices = list_output_devices()?;
+    let device = find_device(&devices, name)
+        .ok_or_else(|| anyhow!("Audio device '{}' is not connected", name))?;
+    set_default_endpoint(&device.id)
+}
+
+/// List active (plugged in, enabled) audio output devices
+#[cfg(windows)]
+pub fn list_output_devices() -> Result<Vec<AudioDevice>> {
+    use windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+    use windows::Win32::Media::Audio::{
+        eRender, IMMDeviceEnumerator, MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
+    };
+    use windows::Win32::System::Com::StructuredStorage::PropVariantClear;
+    use windows::Win32::System::Com::{CoCreateInstance, CoTaskMemFree, CLSCTX_ALL, STGM_READ};
+
+    init_com();
+
+    unsafe {
+        let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
+            .map_err(|e| anyhow!("Failed to create device enumerator: {}", e))?;
+        let collection = enumerator
+            .EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)
+            .map_err(|e| anyhow!("Failed to enumerate audio devices: {}", e))?;
+
+        let mut devices = Vec::new();
+        for i in 0..collection.GetCount()? {
+            let device = collection.Item(i)?;
+
+            let id_ptr = device.GetId()?;
+            let id = id_ptr.to_string().unwrap_or_default();
+            CoTaskMemFree(Some(id_ptr.0 as *const _));
+
+            let store = device.OpenPropertyStore(STGM_READ)?;
+            let mut value = store.GetValue(&PKEY_Device_FriendlyName)?;
+            let name = value.Anonymous.Anonymous.Anonymous.pwszVal.to_string().unwrap_or_default();
+            let _ = PropVariantClear(&mut value);
+
+            devices.push(AudioDevice { id, name });
+        }
+
+        Ok(devices)
+    }
+}
+
+#[cfg(not(windows))]
+pub fn list_output_devices() -> Result<Vec<AudioDevice>> {
+    Ok(Vec::new())
+}
+
+#[cfg(windows)]
+fn init_com() {
+    use windows::Win32::System::Com::{CoInitializeEx, COINIT_APARTMENTTHREADED};
+    // Already initialized on the GUI thread is fine (S_FALSE / RPC_E_CHANGED_MODE)
+    let _ = unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED) };
+}
+
+/// IPolicyConfig isn't in the Windows SDK headers, so call it through its vtable
+#[cfg(windows)]
+fn set_default_endpoint(device_id: &str) -> Result<()> {
+    use std::ffi::c_void;
+    use windows::core::{IUnknown, Interface, GUID, HRESULT, PCWSTR};
+    use windows::Win32::Media::Audio::{eCommunications, eConsole, eMultimedia, ERole};
+    use windows::Win32::System::Com::{CoCreateInstance, CLSCTX_ALL};
+
+    const CLSID_POLICY_CONFIG_CLIENT: GUID = GUID::from_u128(0x870af99c_171d_4f9e_af0d_e63df40c2bc9);
+    const IID_POLICY_CONFIG: GUID = GUID::from_u128(0xf8679f50_850a_41cf_9c72_430f290290c8);
+    // IUnknown (3) + GetMixFormat .. SetPropertyValue (10)
+    const SET_DEFAULT_ENDPOINT_SLOT: usize = 13;
+
+    type SetDefaultEndpointFn = unsafe extern "system" fn(*mut c_void, PCWSTR, ERole) -> HRESULT;
+
+    init_com();
+
+    let wide_id: Vec<u16> = device_id.encode_utf16().chain(Some(0)).collect();
+
+    unsafe {
+        let unknown: IUnknown = CoCreateInstance(&CLSID_POLICY_CONFIG_CLIENT, None, CLSCTX_ALL)
+            .map_err(|e| anyhow!("Audio policy interface unavailable: {}", e))?;
+
+        let mut policy: *mut c_void = std::ptr::null_mut();
+        unknown
+            .query(&IID_POLICY_CONFIG, &mut policy)
+            .ok()
+            .map_err(|e| anyhow!("Audio policy interface unavailable: {}", e))?;
+        // Takes ownership of the reference from query() so it is released on drop
+        let policy_ref = IUnknown::from_raw(policy);
+
+        let vtable = *(policy as *const *const usize);
+        let set_default: SetDefaultEndpointFn = std::mem::transmute(*vtable.add(SET_DEFAULT_ENDPOINT_SLOT));
+
+        for role in [eConsole, eMultimedia, eCommunications] {
+            set_default(policy, PCWSTR(wide_id.as_ptr()), role)
+                .ok()
+                .map_err(|e| anyhow!("Failed to set default audio device: {}", e))?;
+        }
+
+        drop(policy_ref);
+    }
+
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn set_default_endpoint(_device_id: &str) -> Result<()> {
+    Err(anyhow!("Audio device switching is only supported on Windows"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_device() {
+        let devices = vec![
+            AudioDevice { id: "a".to_string(), name: "Speakers (Realtek)".to_string() },
+            AudioDevice { id: "b".to_string(), name: "Headphones (Arctis 7)".to_string() },
+        ];
+        assert_eq!(find_device(&devices, "headphones (arctis 7)").map(|d| d.id.as_str()), Some("b"));
+        assert!(find_device(&devices, "HDMI").is_none());
+    }
+}
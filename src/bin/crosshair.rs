@@ -1,39 +1,228 @@
 //! Standalone crosshair overlay - works over fullscreen games
 //! Uses DWM composition like Xbox Game Bar, Discord, and NVIDIA overlays
-//! Usage: crosshair.exe <image_path> <x_offset> <y_offset>
+//! Usage: crosshair.exe <image_path> <x_offset> <y_offset>   (image_path may be builtin:default)
+//!        crosshair.exe --shape <dot|cross|circle> <size> <thickness> <RRGGBB> <gap> <x_offset> <y_offset>
+//! Animated GIF/APNG crosshairs cycle through their frames at each frame's own delay
+//! Optional leading flags:
+//!   --refresh-hz <n>    fixed loop rate (default: idle when static, ~60Hz when animated)
+//!   --no-topmost-loop   don't re-assert topmost on a timer (windowed/borderless use)
+//!   --topmost-interval <ms>  how often the topmost loop re-asserts (default 100)
+//!   --topmost-on-foreground  also re-assert topmost whenever the foreground window changes
+//!   --focus-process <exe>    only show the crosshair while that process (or the app) is focused
+//!   --parent-pid <pid>  exit when that process exits (no ghost crosshair after a crash)
+//!   --scale <percent>   resize the crosshair (default 100)
+//!   --opacity <percent> fade the crosshair (default 100)
+//!   --selftest <path> <ms>  exit after <ms> and write each loop tick's gap (µs) to <path>
 
 #![windows_subsystem = "windows"]
 
+#[path = "../assets.rs"]
+#[allow(dead_code)]
+mod assets;
+
+#[path = "../crosshair_shapes.rs"]
+#[allow(dead_code)]
+mod crosshair_shapes;
+
+use crosshair_shapes::CrosshairShape;
+use image::{AnimationDecoder, RgbaImage};
 use std::env;
+use std::fs::File;
+use std::io::BufReader;
 use std::path::Path;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    
-    if args.len() < 4 {
-        return;
+    let mut args: Vec<String> = env::args().collect();
+    
+    // Optional leading flags, stripped so the positional arguments below are unchanged
+    let mut refresh_hz: Option<u32> = None;
+    let mut aggressive_topmost = true;
+    let mut topmost_interval_ms: u32 = 100;
+    let mut topmost_on_foreground = false;
+    let mut focus_process: Option<String> = None;
+    let mut parent_pid: Option<u32> = None;
+    let mut scale_percent: u32 = 100;
+    let mut opacity_percent: u32 = 100;
+    let mut self_test: Option<SelfTest> = None;
+    loop {
+        match args.get(1).map(String::as_str) {
+            Some("--refresh-hz") if args.len() > 2 => {
+                refresh_hz = args[2].parse().ok().filter(|hz| *hz > 0);
+                args.drain(1..3);
+            }
+            Some("--no-topmost-loop") => {
+                aggressive_topmost = false;
+                args.remove(1);
+            }
+            Some("--topmost-interval") if args.len() > 2 => {
+                topmost_interval_ms = args[2].parse::<u32>().unwrap_or(100).clamp(10, 10_000);
+                args.drain(1..3);
+            }
+            Some("--topmost-on-foreground") => {
+                topmost_on_foreground = true;
+                args.remove(1);
+            }
+            Some("--focus-process") if args.len() > 2 => {
+                focus_process = Some(args[2].clone());
+                args.drain(1..3);
+            }
+            Some("--parent-pid") if args.len() > 2 => {
+                parent_pid = args[2].parse().ok();
+                args.drain(1..3);
+            }
+            Some("--scale") if args.len() > 2 => {
+                scale_percent = args[2].parse::<u32>().unwrap_or(100).clamp(10, 400);
+                args.drain(1..3);
+            }
+            Some("--opacity") if args.len() > 2 => {
+                opacity_percent = args[2].parse::<u32>().unwrap_or(100).min(100);
+                args.drain(1..3);
+            }
+            Some("--selftest") if args.len() > 3 => {
+                self_test = Some(SelfTest {
+                    log_path: args[2].clone(),
+                    run_ms: args[3].parse().unwrap_or(3000),
+                });
+                args.drain(1..4);
+            }
+            _ => break,
+        }
     }
     
-    let image_path = &args[1];
-    let x_offset: i32 = args[2].parse().unwrap_or(0);
-    let y_offset: i32 = args[3].parse().unwrap_or(0);
+    // (image, delay in ms) - a single frame for static crosshairs
+    let frames: Vec<(RgbaImage, u32)> = if args.get(1).map(String::as_str) == Some("--shape") {
+        // Procedural crosshair: --shape <kind> <size> <thickness> <color> <gap> <x> <y>
+        if args.len() < 9 {
+            return;
+        }
+        
+        let shape = match CrosshairShape::from_args(&args[2..7]) {
+            Some(shape) => shape,
+            None => return,
+        };
+        
+        let (pixels, width, height) = shape.render_rgba();
+        match RgbaImage::from_raw(width, height, pixels) {
+            Some(rgba) => vec![(rgba, 0)],
+            None => return,
+        }
+    } else {
+        if args.len() < 4 {
+            return;
+        }
+        
+        let image_path = &args[1];
+        if !assets::is_builtin(image_path) && !Path::new(image_path).exists() {
+            return;
+        }
+        
+        match load_frames(Path::new(image_path)) {
+            Some(frames) => frames,
+            None => return,
+        }
+    };
+    
+    let frames: Vec<(RgbaImage, u32)> = frames
+        .into_iter()
+        .map(|(rgba, delay_ms)| (adjust_frame(rgba, scale_percent, opacity_percent), delay_ms))
+        .collect();
     
-    if !Path::new(image_path).exists() {
+    // Offsets are always the last two arguments
+    let x_offset: i32 = args[args.len() - 2].parse().unwrap_or(0);
+    let y_offset: i32 = args[args.len() - 1].parse().unwrap_or(0);
+    
+    let Some((first, _)) = frames.first() else {
         return;
+    };
+    let width = first.width();
+    let height = first.height();
+    
+    // Pre-convert every frame once; frames of a different size can't share the bitmap
+    let bgra_frames: Vec<(Vec<u8>, u32)> = frames
+        .iter()
+        .filter(|(rgba, _)| rgba.dimensions() == (width, height))
+        .map(|(rgba, delay_ms)| (to_premultiplied_bgra(rgba), *delay_ms))
+        .collect();
+    
+    let topmost = TopmostPolicy {
+        interval_ms: aggressive_topmost.then_some(topmost_interval_ms),
+        on_foreground: topmost_on_foreground,
+    };
+    
+    #[cfg(windows)]
+    unsafe {
+        run_overlay(bgra_frames, width, height, x_offset, y_offset, refresh_hz, topmost, focus_process, parent_pid, self_test);
+    }
+}
+
+/// Load a crosshair image; GIFs and APNGs yield all their frames
+fn load_frames(path: &Path) -> Option<Vec<(RgbaImage, u32)>> {
+    // builtin: images are embedded in the exe and always static
+    if let Some(bytes) = path.to_str().and_then(assets::builtin_image) {
+        return Some(vec![(assets::decode(bytes).ok()?, 0)]);
     }
     
-    // Load image
-    let img = match image::open(image_path) {
-        Ok(img) => img,
-        Err(_) => return,
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    
+    let animation = match extension.as_str() {
+        "gif" => {
+            let reader = BufReader::new(File::open(path).ok()?);
+            image::codecs::gif::GifDecoder::new(reader).ok()?.into_frames().collect_frames().ok()
+        }
+        "png" => {
+            let reader = BufReader::new(File::open(path).ok()?);
+            let decoder = image::codecs::png::PngDecoder::new(reader).ok()?;
+            if decoder.is_apng() {
+                decoder.apng().into_frames().collect_frames().ok()
+            } else {
+                None
+            }
+        }
+        _ => None,
     };
     
-    let rgba = img.to_rgba8();
-    let width = rgba.width();
-    let height = rgba.height();
+    match animation {
+        Some(frames) if frames.len() > 1 => Some(
+            frames
+                .into_iter()
+                .map(|frame| {
+                    let (numer, denom) = frame.delay().numer_denom_ms();
+                    let delay_ms = numer / denom.max(1);
+                    // Like browsers, treat near-zero delays as 100ms
+                    let delay_ms = if delay_ms < 20 { 100 } else { delay_ms };
+                    (frame.into_buffer(), delay_ms)
+                })
+                .collect(),
+        ),
+        // Static image (or a single-frame animation)
+        _ => Some(vec![(image::open(path).ok()?.to_rgba8(), 0)]),
+    }
+}
+
+/// Apply --scale and --opacity to one frame
+fn adjust_frame(rgba: RgbaImage, scale_percent: u32, opacity_percent: u32) -> RgbaImage {
+    let mut rgba = if scale_percent == 100 {
+        rgba
+    } else {
+        let width = (rgba.width() * scale_percent / 100).max(1);
+        let height = (rgba.height() * scale_percent / 100).max(1);
+        image::imageops::resize(&rgba, width, height, image::imageops::FilterType::Triangle)
+    };
     
-    // Convert to BGRA (premultiplied alpha for UpdateLayeredWindow)
-    let mut bgra_pixels: Vec<u8> = Vec::with_capacity((width * height * 4) as usize);
+    if opacity_percent < 100 {
+        for pixel in rgba.pixels_mut() {
+            pixel[3] = (pixel[3] as u32 * opacity_percent / 100) as u8;
+        }
+    }
+    rgba
+}
+
+/// Convert to BGRA (premultiplied alpha for UpdateLayeredWindow)
+fn to_premultiplied_bgra(rgba: &RgbaImage) -> Vec<u8> {
+    let mut bgra_pixels: Vec<u8> = Vec::with_capacity((rgba.width() * rgba.height() * 4) as usize);
     for pixel in rgba.pixels() {
         let a = pixel[3] as f32 / 255.0;
         // Premultiply alpha for proper blending
@@ -42,39 +231,58 @@ fn main() {
         bgra_pixels.push((pixel[0] as f32 * a) as u8); // R
         bgra_pixels.push(pixel[3]);                     // A
     }
-    
-    #[cfg(windows)]
-    unsafe {
-        run_overlay(bgra_pixels, width, height, x_offset, y_offset);
-    }
+    bgra_pixels
+}
+
+/// When the overlay re-asserts HWND_TOPMOST against the game
+#[derive(Debug, Clone, Copy)]
+struct TopmostPolicy {
+    /// Re-assert on a timer every this many ms (None = never)
+    interval_ms: Option<u32>,
+    /// Re-assert whenever the foreground window changes (SetWinEventHook)
+    on_foreground: bool,
+}
+
+/// --selftest: run for a fixed time and record loop timing for the app's --overlay-selftest
+struct SelfTest {
+    log_path: String,
+    run_ms: u32,
 }
 
 #[cfg(windows)]
+#[allow(clippy::too_many_arguments)]
 unsafe fn run_overlay(
-    pixels: Vec<u8>,
+    frames: Vec<(Vec<u8>, u32)>,
     img_width: u32,
     img_height: u32,
     x_offset: i32,
     y_offset: i32,
+    refresh_hz: Option<u32>,
+    topmost: TopmostPolicy,
+    focus_process: Option<String>,
+    parent_pid: Option<u32>,
+    self_test: Option<SelfTest>,
 ) {
     use std::mem::zeroed;
     use std::ptr::null_mut;
     
-    use windows::Win32::Foundation::{COLORREF, HWND, HINSTANCE, POINT, SIZE};
+    use windows::Win32::Foundation::{CloseHandle, COLORREF, HWND, HINSTANCE, POINT, SIZE, WAIT_OBJECT_0};
+    use windows::Win32::System::Threading::{OpenProcess, PROCESS_SYNCHRONIZE};
     use windows::Win32::Graphics::Gdi::{
         CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject,
         GetDC, ReleaseDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER,
         BI_RGB, DIB_RGB_COLORS, AC_SRC_ALPHA, AC_SRC_OVER, BLENDFUNCTION,
     };
     use windows::Win32::Graphics::Dwm::DwmExtendFrameIntoClientArea;
+    use windows::Win32::UI::Accessibility::{SetWinEventHook, UnhookWinEvent};
     use windows::Win32::UI::Controls::MARGINS;
     use windows::Win32::System::LibraryLoader::GetModuleHandleW;
     use windows::Win32::UI::WindowsAndMessaging::{
-        CreateWindowExW, DispatchMessageW, PeekMessageW,
+        CreateWindowExW, DispatchMessageW, GetForegroundWindow, MsgWaitForMultipleObjects, PeekMessageW, PostQuitMessage, QS_ALLINPUT,
         GetSystemMetrics, RegisterClassExW, SetWindowPos, ShowWindow,
         UpdateLayeredWindow, CS_HREDRAW, CS_VREDRAW, HWND_TOPMOST,
         MSG, PM_REMOVE, SM_CXSCREEN, SM_CYSCREEN, SWP_NOMOVE, SWP_NOSIZE,
-        SWP_NOACTIVATE, SW_SHOWNA, WNDCLASSEXW, WS_EX_LAYERED, WS_EX_TOOLWINDOW,
+        SWP_NOACTIVATE, SW_SHOWNA, WNDCLASSEXW, EVENT_SYSTEM_FOREGROUND, WINEVENT_OUTOFCONTEXT, WS_EX_LAYERED, WS_EX_TOOLWINDOW,
         WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_EX_NOACTIVATE, WS_POPUP,
         ULW_ALPHA,
     };
@@ -88,7 +296,7 @@ unsafe fn run_overlay(
     let win_x = (screen_w / 2) - (img_width as i32 / 2) + x_offset;
     let win_y = (screen_h / 2) - (img_height as i32 / 2) + y_offset;
     
-    // Unique class name
+    // Unique class name (the GUI looks the window up by it for positioning mode)
     let class_name: Vec<u16> = "CrosshairDWMOverlay\0".encode_utf16().collect();
     
     let hinstance = match GetModuleHandleW(PCWSTR::null()) {
@@ -130,9 +338,9 @@ unsafe fn run_overlay(
         return;
     }
     
-    // Copy premultiplied alpha pixels
+    // Copy premultiplied alpha pixels of the first frame
     let dst = std::slice::from_raw_parts_mut(bits_ptr as *mut u8, (img_width * img_height * 4) as usize);
-    dst.copy_from_slice(&pixels);
+    dst.copy_from_slice(&frames[0].0);
     
     let old_obj = SelectObject(mem_dc, hbitmap);
     
@@ -229,11 +437,59 @@ unsafe fn run_overlay(
     // Store for cleanup
     GLOBAL_HWND = Some(hwnd);
     
+    // Start hidden if the game isn't in front yet; the hook below shows it once it is
+    if let Some(process) = focus_process {
+        let _ = FOCUS_RULE.set(FocusRule { process, parent_pid });
+        apply_focus_rule(GetForegroundWindow());
+    }
+    
+    // Static crosshairs only need the topmost re-assert, so idle at that interval (10Hz
+    // without one); animations tick at ~60Hz so frame delays are honored closely
+    let tick_ms: u32 = match refresh_hz {
+        Some(hz) => (1000 / hz).max(1),
+        None if frames.len() > 1 => 16,
+        None => topmost.interval_ms.unwrap_or(100),
+    };
+    
+    // Foreground changes are delivered to this thread while it pumps messages below
+    let foreground_hook = if topmost.on_foreground || FOCUS_RULE.get().is_some() {
+        let hook = SetWinEventHook(
+            EVENT_SYSTEM_FOREGROUND,
+            EVENT_SYSTEM_FOREGROUND,
+            None,
+            Some(on_foreground_changed),
+            0,
+            0,
+            WINEVENT_OUTOFCONTEXT,
+        );
+        (!hook.is_invalid()).then_some(hook)
+    } else {
+        None
+    };
+    
+    // The parent's process handle becomes signaled when it exits, however it exits.
+    // If it can't be opened the overlay just runs unsupervised as before.
+    let parent = parent_pid.and_then(|pid| OpenProcess(PROCESS_SYNCHRONIZE, false, pid).ok());
+    let wait_handles: Vec<_> = parent.into_iter().collect();
+    
     // Message loop with periodic topmost refresh
     let mut msg: MSG = zeroed();
-    let mut counter: u32 = 0;
+    let mut last_topmost = std::time::Instant::now();
+    let mut frame_index = 0;
+    let mut frame_started = std::time::Instant::now();
+    let started = std::time::Instant::now();
+    let mut last_tick = started;
+    let mut tick_gaps_us: Vec<u128> = Vec::new();
     
     loop {
+        if let Some(ref test) = self_test {
+            tick_gaps_us.push(last_tick.elapsed().as_micros());
+            last_tick = std::time::Instant::now();
+            if started.elapsed().as_millis() >= test.run_ms as u128 {
+                PostQuitMessage(0);
+            }
+        }
+        
         // Process messages (non-blocking)
         while PeekMessageW(&mut msg, HWND::default(), 0, 0, PM_REMOVE).as_bool() {
             if msg.message == 0x0012 { // WM_QUIT
@@ -241,22 +497,147 @@ unsafe fn run_overlay(
                 SelectObject(mem_dc, old_obj);
                 let _ = DeleteObject(hbitmap);
                 let _ = DeleteDC(mem_dc);
+                if let Some(parent) = parent {
+                    let _ = CloseHandle(parent);
+                }
+                if let Some(hook) = foreground_hook {
+                    let _ = UnhookWinEvent(hook);
+                }
+                if let Some(ref test) = self_test {
+                    // The first gap is just the loop starting, not a tick
+                    let lines: Vec<String> = tick_gaps_us.iter().skip(1).map(u128::to_string).collect();
+                    let _ = std::fs::write(&test.log_path, lines.join("\n"));
+                }
                 GLOBAL_HWND = None;
                 return;
             }
             let _ = DispatchMessageW(&msg);
         }
         
-        // Every ~100ms, re-assert topmost (fights fullscreen games)
-        counter = counter.wrapping_add(1);
-        if counter % 6 == 0 {
+        // Advance animated crosshairs by each frame's own delay, not by loop ticks
+        if frames.len() > 1 && frame_started.elapsed().as_millis() >= frames[frame_index].1 as u128 {
+            frame_index = (frame_index + 1) % frames.len();
+            frame_started = std::time::Instant::now();
+            dst.copy_from_slice(&frames[frame_index].0);
+            
+            // No destination point: keep wherever the window is (it may have been dragged)
+            let frame_dc = GetDC(HWND::default());
+            let _ = UpdateLayeredWindow(
+                hwnd,
+                frame_dc,
+                None,
+                Some(&size),
+                mem_dc,
+                Some(&src_point),
+                COLORREF(0),
+                Some(&blend),
+                ULW_ALPHA,
+            );
+            ReleaseDC(HWND::default(), frame_dc);
+        }
+        
+        // Periodically re-assert topmost (fights fullscreen games, but can flicker against
+        // other topmost windows in windowed mode - hence the opt-out)
+        if topmost.interval_ms.is_some_and(|ms| last_topmost.elapsed().as_millis() >= ms as u128) {
             let _ = SetWindowPos(hwnd, HWND_TOPMOST, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
+            last_topmost = std::time::Instant::now();
         }
         
-        std::thread::sleep(std::time::Duration::from_millis(16));
+        // Sleep until the next tick, but wake immediately for input (e.g. dragging in positioning mode)
+        // or when the parent app exits
+        let woke = MsgWaitForMultipleObjects(Some(&wait_handles), false, tick_ms, QS_ALLINPUT);
+        if parent.is_some() && woke == WAIT_OBJECT_0 {
+            // Cleanup happens on the WM_QUIT path above
+            PostQuitMessage(0);
+        }
     }
 }
 
+/// A new window came to the foreground (a game starting or alt-tabbed to): get back on top of it
+#[cfg(windows)]
+unsafe extern "system" fn on_foreground_changed(
+    _hook: windows::Win32::UI::Accessibility::HWINEVENTHOOK,
+    _event: u32,
+    foreground: windows::Win32::Foundation::HWND,
+    _id_object: i32,
+    _id_child: i32,
+    _event_thread: u32,
+    _event_time: u32,
+) {
+    use windows::Win32::UI::WindowsAndMessaging::{
+        SetWindowPos, HWND_TOPMOST, SWP_NOACTIVATE, SWP_NOMOVE, SWP_NOSIZE,
+    };
+    
+    if !apply_focus_rule(foreground) {
+        return;
+    }
+    if let Some(hwnd) = GLOBAL_HWND {
+        let _ = SetWindowPos(hwnd, HWND_TOPMOST, 0, 0, 0, 0, SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE);
+    }
+}
+
+/// --focus-process: the crosshair is only shown while this process is in front
+#[cfg(windows)]
+struct FocusRule {
+    process: String,
+    /// The app itself also counts, so the crosshair stays visible for positioning mode
+    parent_pid: Option<u32>,
+}
+
+#[cfg(windows)]
+static FOCUS_RULE: std::sync::OnceLock<FocusRule> = std::sync::OnceLock::new();
+
+/// Show or hide the crosshair for the new foreground window; returns whether it is shown
+#[cfg(windows)]
+unsafe fn apply_focus_rule(foreground: windows::Win32::Foundation::HWND) -> bool {
+    use windows::Win32::UI::WindowsAndMessaging::{ShowWindow, SW_HIDE, SW_SHOWNA};
+    
+    let Some(rule) = FOCUS_RULE.get() else {
+        return true;
+    };
+    let shown = match window_process(foreground) {
+        Some((pid, name)) => {
+            Some(pid) == rule.parent_pid
+                || name.eq_ignore_ascii_case(&rule.process)
+                || name.eq_ignore_ascii_case(&format!("{}.exe", rule.process))
+        }
+        // Nothing focused (e.g. mid alt-tab): leave the crosshair as it is
+        None => return true,
+    };
+    if let Some(hwnd) = GLOBAL_HWND {
+        let _ = ShowWindow(hwnd, if shown { SW_SHOWNA } else { SW_HIDE });
+    }
+    shown
+}
+
+/// PID and executable name of the process owning a window
+#[cfg(windows)]
+unsafe fn window_process(hwnd: windows::Win32::Foundation::HWND) -> Option<(u32, String)> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::GetWindowThreadProcessId;
+    
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if pid == 0 {
+        return None;
+    }
+    
+    let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+    let mut buffer = [0u16; 260];
+    let mut len = buffer.len() as u32;
+    let queried = QueryFullProcessImageNameW(process, PROCESS_NAME_WIN32, PWSTR(buffer.as_mut_ptr()), &mut len);
+    let _ = CloseHandle(process);
+    queried.ok()?;
+    
+    let path = String::from_utf16_lossy(&buffer[..len as usize]);
+    let name = path.rsplit('\\').next().unwrap_or(&path).to_string();
+    Some((pid, name))
+}
+
 #[cfg(windows)]
 static mut GLOBAL_HWND: Option<windows::Win32::Foundation::HWND> = None;
 
@@ -268,16 +649,25 @@ unsafe extern "system" fn wnd_proc(
     lparam: windows::Win32::Foundation::LPARAM,
 ) -> windows::Win32::Foundation::LRESULT {
     use windows::Win32::Foundation::LRESULT;
-    use windows::Win32::UI::WindowsAndMessaging::{DefWindowProcW, PostQuitMessage};
+    use windows::Win32::UI::WindowsAndMessaging::{
+        DefWindowProcW, GetWindowLongPtrW, PostQuitMessage, GWL_EXSTYLE, WS_EX_TRANSPARENT,
+    };
     
     const WM_DESTROY: u32 = 0x0002;
     const WM_NCHITTEST: u32 = 0x0084;
     const HTTRANSPARENT: i32 = -1;
+    const HTCAPTION: i32 = 2;
     
     match msg {
         WM_NCHITTEST => {
-            // Make window completely click-through
-            LRESULT(HTTRANSPARENT as isize)
+            // Click-through normally; the GUI clears WS_EX_TRANSPARENT for positioning mode,
+            // in which case the whole crosshair acts as a caption so it can be dragged
+            let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+            if ex_style & WS_EX_TRANSPARENT.0 as isize != 0 {
+                LRESULT(HTTRANSPARENT as isize)
+            } else {
+                LRESULT(HTCAPTION as isize)
+            }
         }
         WM_DESTROY => {
             PostQuitMessage(0);
@@ -1,5 +1,8 @@
 /// Common applications selector for process management
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommonApp {
@@ -63,6 +66,93 @@ pub const COMMON_APPS: &[(&str, &str)] = &[
     ("Windows 11 Game Bar", "GameBarFTDesktopComp.exe"),
 ];
 
+/// Where the common apps usually live, so they can be offered before they're running
+/// Paths start with an environment variable; any existing entry counts as installed.
+const INSTALL_PATHS: &[(&str, &[&str])] = &[
+    ("Discord.exe", &["%LOCALAPPDATA%\\Discord"]),
+    ("DiscordCanary.exe", &["%LOCALAPPDATA%\\DiscordCanary"]),
+    ("Telegram.exe", &["%APPDATA%\\Telegram Desktop\\Telegram.exe"]),
+    ("slack.exe", &["%LOCALAPPDATA%\\slack"]),
+    ("Zoom.exe", &["%APPDATA%\\Zoom\\bin\\Zoom.exe"]),
+    ("obs64.exe", &["%ProgramFiles%\\obs-studio\\bin\\64bit\\obs64.exe"]),
+    ("obs32.exe", &["%ProgramFiles(x86)%\\obs-studio\\bin\\32bit\\obs32.exe"]),
+    ("XSplitBroadcaster.exe", &["%ProgramFiles%\\XSplit"]),
+    ("Streamlabs OBS.exe", &["%ProgramFiles%\\Streamlabs OBS"]),
+    ("TwitchStudio.exe", &["%LOCALAPPDATA%\\Programs\\twitch-studio"]),
+    ("Spotify.exe", &["%APPDATA%\\Spotify\\Spotify.exe"]),
+    ("YouTubeMusic.exe", &["%LOCALAPPDATA%\\Programs\\youtube-music"]),
+    ("vlc.exe", &["%ProgramFiles%\\VideoLAN\\VLC\\vlc.exe", "%ProgramFiles(x86)%\\VideoLAN\\VLC\\vlc.exe"]),
+    ("foobar2000.exe", &["%ProgramFiles%\\foobar2000", "%ProgramFiles(x86)%\\foobar2000"]),
+    ("Steam.exe", &["%ProgramFiles(x86)%\\Steam\\steam.exe"]),
+    ("EpicGamesLauncher.exe", &["%ProgramFiles(x86)%\\Epic Games\\Launcher"]),
+    ("GalaxyClient.exe", &["%ProgramFiles(x86)%\\GOG Galaxy\\GalaxyClient.exe"]),
+    ("Battle.net.exe", &["%ProgramFiles(x86)%\\Battle.net"]),
+    ("UbisoftConnect.exe", &["%ProgramFiles(x86)%\\Ubisoft\\Ubisoft Game Launcher"]),
+    ("chrome.exe", &["%ProgramFiles%\\Google\\Chrome\\Application\\chrome.exe", "%ProgramFiles(x86)%\\Google\\Chrome\\Application\\chrome.exe"]),
+    ("firefox.exe", &["%ProgramFiles%\\Mozilla Firefox\\firefox.exe"]),
+    ("msedge.exe", &["%ProgramFiles(x86)%\\Microsoft\\Edge\\Application\\msedge.exe"]),
+    ("OneDrive.exe", &["%LOCALAPPDATA%\\Microsoft\\OneDrive\\OneDrive.exe", "%ProgramFiles%\\Microsoft OneDrive\\OneDrive.exe"]),
+    ("Dropbox.exe", &["%ProgramFiles(x86)%\\Dropbox", "%ProgramFiles%\\Dropbox"]),
+    ("GoogleDriveFS.exe", &["%ProgramFiles%\\Google\\Drive File Stream"]),
+    ("iCloudServices.exe", &["%ProgramFiles%\\Common Files\\Apple\\Internet Services\\iCloudServices.exe"]),
+    ("MsMpEng.exe", &["%ProgramData%\\Microsoft\\Windows Defender\\Platform"]),
+    ("NortonLifeLock.exe", &["%ProgramFiles%\\NortonLifeLock"]),
+    ("McShield.exe", &["%ProgramFiles%\\McAfee"]),
+    ("Code.exe", &["%LOCALAPPDATA%\\Programs\\Microsoft VS Code\\Code.exe", "%ProgramFiles%\\Microsoft VS Code\\Code.exe"]),
+    ("devenv.exe", &["%ProgramFiles%\\Microsoft Visual Studio"]),
+    ("idea64.exe", &["%ProgramFiles%\\JetBrains"]),
+    ("jetbrains-client.exe", &["%LOCALAPPDATA%\\JetBrains"]),
+];
+
+/// Whether a common app can be found on this machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AppStatus {
+    Running,
+    Installed,
+    NotFound,
+}
+
+impl fmt::Display for AppStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppStatus::Running => write!(f, "running"),
+            AppStatus::Installed => write!(f, "installed"),
+            AppStatus::NotFound => write!(f, "not found"),
+        }
+    }
+}
+
+/// Expand a leading `%VAR%` in an install path, None if the variable isn't set
+fn expand_install_path(path: &str, lookup: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let Some(rest) = path.strip_prefix('%') else {
+        return Some(PathBuf::from(path));
+    };
+    let (var, tail) = rest.split_once('%')?;
+    let base = lookup(var)?;
+    Some(PathBuf::from(format!("{}{}", base, tail)))
+}
+
+/// Check the known install locations for an executable
+pub fn is_installed(executable: &str) -> bool {
+    INSTALL_PATHS
+        .iter()
+        .filter(|(exe, _)| exe.eq_ignore_ascii_case(executable))
+        .flat_map(|(_, paths)| paths.iter())
+        .filter_map(|path| expand_install_path(path, |var| std::env::var(var).ok()))
+        .any(|path| path.exists())
+}
+
+/// Running beats installed; `running` holds lowercase process names
+pub fn app_status(executable: &str, running: &HashSet<String>) -> AppStatus {
+    if running.contains(&executable.to_lowercase()) {
+        AppStatus::Running
+    } else if is_installed(executable) {
+        AppStatus::Installed
+    } else {
+        AppStatus::NotFound
+    }
+}
+
 pub fn get_common_apps() -> Vec<CommonApp> {
     COMMON_APPS
         .iter()
@@ -79,3 +169,26 @@ pub fn find_app_by_executable(executable: &str) -> Option<&'static (&'static str
         .iter()
         .find(|(_, exe)| exe.eq_ignore_ascii_case(executable))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_install_path() {
+        let lookup = |var: &str| (var == "ProgramFiles(x86)").then(|| "C:\\Program Files (x86)".to_string());
+        assert_eq!(
+            expand_install_path("%ProgramFiles(x86)%\\Steam\\steam.exe", lookup),
+            Some(PathBuf::from("C:\\Program Files (x86)\\Steam\\steam.exe"))
+        );
+        assert_eq!(expand_install_path("%LOCALAPPDATA%\\Discord", lookup), None);
+    }
+
+    #[test]
+    fn test_app_status() {
+        let running: HashSet<String> = ["discord.exe".to_string()].into_iter().collect();
+        assert_eq!(app_status("Discord.exe", &running), AppStatus::Running);
+        assert_eq!(app_status("definitely_not_installed_12345.exe", &running), AppStatus::NotFound);
+        assert!(INSTALL_PATHS.iter().all(|(exe, _)| find_app_by_executable(exe).is_some()));
+    }
+}
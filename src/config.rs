@@ -11,6 +11,119 @@ pub struct AppConfig {
     pub active_profile: Option<String>,
     /// Whether overlay is currently visible
     pub overlay_visible: bool,
+    /// Closing the main window hides it to the tray instead of exiting
+    #[serde(default)]
+    pub close_to_tray: bool,
+    /// Show a desktop notification when a profile is activated or deactivated
+    #[serde(default = "default_show_notifications")]
+    pub show_notifications: bool,
+    /// Draw the tray flyout over the Windows 11 acrylic backdrop (ignored on Windows 10)
+    #[serde(default = "default_flyout_backdrop")]
+    pub flyout_backdrop: bool,
+    /// Write the app's log output to logs/edge-optimizer.log in the data directory
+    #[serde(default = "default_log_to_file")]
+    pub log_to_file: bool,
+    /// Switching away from an active profile asks for confirmation first
+    #[serde(default)]
+    pub lock_active_profile: bool,
+    /// Never play profile activation sounds, whatever the profiles say
+    #[serde(default)]
+    pub mute_sounds: bool,
+    /// Page open when the GUI was last used ("profiles" or "logs"), reopened on launch
+    #[serde(default)]
+    pub last_page: String,
+    /// Profile open in the editor when the GUI was last used
+    #[serde(default)]
+    pub last_selected_profile: Option<String>,
+    /// Launch with the main window hidden until the tray icon is used (same as --start-minimized)
+    #[serde(default)]
+    pub start_minimized: bool,
+    /// Apps that may hold unsaved work; killing a running one needs confirmation (or --force)
+    #[serde(default = "default_confirm_before_killing")]
+    pub confirm_before_killing: Vec<String>,
+    /// Profile activated every time the GUI launches (None = start with nothing active)
+    #[serde(default)]
+    pub default_profile_on_startup: Option<String>,
+    /// Tray flyout size; missing fields keep their defaults
+    #[serde(default)]
+    pub flyout_style: FlyoutStyle,
+}
+
+/// Height of the flyout title, separator and subtitle, below the top padding
+pub const FLYOUT_HEADER_HEIGHT: i32 = 74;
+
+/// Tray flyout dimensions in pixels
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct FlyoutStyle {
+    pub width: i32,
+    pub height: i32,
+    /// Height of one profile row
+    pub item_height: i32,
+    /// Space between the flyout edge and its contents
+    pub padding: i32,
+}
+
+impl Default for FlyoutStyle {
+    /// Matches the PowerToys flyout
+    fn default() -> Self {
+        FlyoutStyle {
+            width: 386,
+            height: 486,
+            item_height: 60,
+            padding: 16,
+        }
+    }
+}
+
+impl FlyoutStyle {
+    /// Limit hand-edited values to sizes the flyout can lay out: rows tall enough for the
+    /// name and summary lines, and room for the header plus at least one row
+    pub fn clamped(self) -> Self {
+        let padding = self.padding.clamp(4, 48);
+        let item_height = self.item_height.clamp(52, 160);
+        FlyoutStyle {
+            width: self.width.clamp(260, 1200),
+            height: self.height.clamp(padding + FLYOUT_HEADER_HEIGHT + item_height + padding, 1600),
+            item_height,
+            padding,
+        }
+    }
+}
+
+fn default_confirm_before_killing() -> Vec<String> {
+    [
+        "WINWORD.EXE",
+        "EXCEL.EXE",
+        "POWERPNT.EXE",
+        "ONENOTE.EXE",
+        "Photoshop.exe",
+        "Illustrator.exe",
+        "Adobe Premiere Pro.exe",
+        "AfterFX.exe",
+        "blender.exe",
+        "notepad++.exe",
+        "Code.exe",
+        "devenv.exe",
+        "chrome.exe",
+        "firefox.exe",
+        "msedge.exe",
+    ]
+    .iter()
+    .map(|name| name.to_string())
+    .collect()
+}
+
+fn default_log_to_file() -> bool {
+    true
+}
+
+fn default_flyout_backdrop() -> bool {
+    true
+}
+
+fn default_show_notifications() -> bool {
+    true
 }
 
 impl Default for AppConfig {
@@ -18,7 +131,31 @@ impl Default for AppConfig {
         AppConfig {
             active_profile: None,
             overlay_visible: false,
+            close_to_tray: false,
+            show_notifications: true,
+            flyout_backdrop: true,
+            log_to_file: true,
+            lock_active_profile: false,
+            start_minimized: false,
+            mute_sounds: false,
+            last_page: String::new(),
+            last_selected_profile: None,
+            confirm_before_killing: default_confirm_before_killing(),
+            default_profile_on_startup: None,
+            flyout_style: FlyoutStyle::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Forget the startup profile if no profile has that name any more (deleted or renamed)
+    /// Returns the forgotten name so the caller can report it
+    pub fn validate_startup_profile<'a>(&mut self, mut profile_names: impl Iterator<Item = &'a str>) -> Option<String> {
+        let name = self.default_profile_on_startup.as_deref()?;
+        if profile_names.any(|existing| existing == name) {
+            return None;
         }
+        self.default_profile_on_startup.take()
     }
 }
 
@@ -85,6 +222,41 @@ mod tests {
         let config = AppConfig::default();
         assert_eq!(config.active_profile, None);
         assert_eq!(config.overlay_visible, false);
+        assert!(!config.close_to_tray);
+        assert!(!config.start_minimized);
+        assert!(config.show_notifications);
+        assert!(config.log_to_file);
+        assert!(config.confirm_before_killing.iter().any(|p| p == "WINWORD.EXE"));
+    }
+
+    #[test]
+    fn test_validate_startup_profile() {
+        let mut config = AppConfig {
+            default_profile_on_startup: Some("Valorant".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.validate_startup_profile(["CS2", "Valorant"].into_iter()), None);
+        assert_eq!(config.default_profile_on_startup.as_deref(), Some("Valorant"));
+
+        assert_eq!(config.validate_startup_profile(["CS2"].into_iter()), Some("Valorant".to_string()));
+        assert_eq!(config.default_profile_on_startup, None);
+        assert_eq!(config.validate_startup_profile(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_flyout_style_from_config() {
+        let config: AppConfig = serde_json::from_str(
+            r#"{"active_profile": null, "overlay_visible": false, "flyout_style": {"width": 520, "item_height": 10}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.flyout_style.width, 520);
+        assert_eq!(config.flyout_style.height, FlyoutStyle::default().height);
+
+        let style = config.flyout_style.clamped();
+        assert_eq!(style.item_height, 52);
+        assert_eq!(FlyoutStyle::default().clamped(), FlyoutStyle::default());
+        assert_eq!(FlyoutStyle { height: 0, ..Default::default() }.clamped().height, 16 + 74 + 60 + 16);
+        assert_eq!(FlyoutStyle { height: 0, padding: 100, ..Default::default() }.clamped().height, 48 + 74 + 60 + 48);
     }
 
     #[test]
@@ -1,27 +1,110 @@
 //! Crosshair overlay launcher - spawns crosshair as a separate detached process
-//! The crosshair process runs independently and survives even if main app closes
+//! The crosshair process runs independently but exits when the app that started it exits
 
 use std::path::Path;
 use std::process::{Command, Stdio};
+use crate::assets;
+use crate::crosshair_shapes::CrosshairShape;
 
-/// Handle to track the crosshair process
+/// Window class registered by crosshair.exe (must match src/bin/crosshair.rs)
+const OVERLAY_CLASS_NAME: &str = "CrosshairDWMOverlay";
+
+/// Handle to track one crosshair process (one per crosshair layer)
 pub struct OverlayHandle {
-    process_name: String,
+    pid: u32,
 }
 
 impl OverlayHandle {
-    /// Kill all crosshair processes
+    /// Kill this crosshair process
     pub fn stop(&self) {
         #[cfg(windows)]
         {
-            // Kill the crosshair process by name
+            // Kill by PID so the other layers keep running
             let _ = Command::new("taskkill")
-                .args(["/F", "/IM", &self.process_name])
+                .args(["/F", "/PID", &self.pid.to_string()])
                 .stdout(Stdio::null())
                 .stderr(Stdio::null())
                 .spawn();
         }
     }
+
+    /// Toggle click-through on the running crosshair window
+    /// With click-through off the crosshair can be dragged with the mouse (positioning mode)
+    pub fn set_click_through(&self, enabled: bool) -> Result<(), String> {
+        #[cfg(windows)]
+        unsafe {
+            use windows::Win32::UI::WindowsAndMessaging::{
+                GetWindowLongPtrW, SetWindowLongPtrW, GWL_EXSTYLE, WS_EX_TRANSPARENT,
+            };
+
+            let hwnd = find_overlay_window(self.pid).ok_or("Crosshair window not found")?;
+            let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+            let new_style = if enabled {
+                ex_style | WS_EX_TRANSPARENT.0 as isize
+            } else {
+                ex_style & !(WS_EX_TRANSPARENT.0 as isize)
+            };
+            SetWindowLongPtrW(hwnd, GWL_EXSTYLE, new_style);
+        }
+
+        #[cfg(not(windows))]
+        let _ = enabled;
+
+        Ok(())
+    }
+
+    /// Read the crosshair window's current offset from screen center
+    /// Returns None if the crosshair window isn't running
+    pub fn current_offset(&self) -> Option<(i32, i32)> {
+        #[cfg(windows)]
+        unsafe {
+            use windows::Win32::Foundation::RECT;
+            use windows::Win32::UI::WindowsAndMessaging::{
+                GetSystemMetrics, GetWindowRect, SM_CXSCREEN, SM_CYSCREEN,
+            };
+
+            let hwnd = find_overlay_window(self.pid)?;
+            let mut rect = RECT::default();
+            GetWindowRect(hwnd, &mut rect).ok()?;
+
+            let screen_w = GetSystemMetrics(SM_CXSCREEN);
+            let screen_h = GetSystemMetrics(SM_CYSCREEN);
+            let width = rect.right - rect.left;
+            let height = rect.bottom - rect.top;
+
+            // Inverse of the centering math in crosshair.exe
+            Some((
+                rect.left - (screen_w / 2 - width / 2),
+                rect.top - (screen_h / 2 - height / 2),
+            ))
+        }
+
+        #[cfg(not(windows))]
+        None
+    }
+}
+
+/// Find the crosshair window owned by the given crosshair.exe process
+#[cfg(windows)]
+fn find_overlay_window(pid: u32) -> Option<windows::Win32::Foundation::HWND> {
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::UI::WindowsAndMessaging::{FindWindowExW, GetWindowThreadProcessId};
+
+    let class_name: Vec<u16> = OVERLAY_CLASS_NAME.encode_utf16().chain(Some(0)).collect();
+    let mut hwnd = HWND(0);
+    loop {
+        hwnd = unsafe { FindWindowExW(None, hwnd, PCWSTR(class_name.as_ptr()), PCWSTR::null()) };
+        if hwnd.0 == 0 {
+            return None;
+        }
+
+        let mut window_pid = 0u32;
+        unsafe { GetWindowThreadProcessId(hwnd, Some(&mut window_pid)) };
+        if window_pid == pid {
+            return Some(hwnd);
+        }
+    }
 }
 
 impl Drop for OverlayHandle {
@@ -31,69 +114,323 @@ impl Drop for OverlayHandle {
     }
 }
 
-/// Start crosshair as a completely separate process
-/// The crosshair will continue running even if the main app closes
+/// What the crosshair process should draw
+#[derive(Debug, Clone)]
+pub enum CrosshairSource {
+    /// PNG image on disk
+    Image(String),
+    /// Procedurally drawn shape (no image file needed)
+    Shape(CrosshairShape),
+}
+
+impl CrosshairSource {
+    /// Leading crosshair.exe arguments describing the source
+    fn to_args(&self) -> Vec<String> {
+        match self {
+            CrosshairSource::Image(path) => vec![path.clone()],
+            CrosshairSource::Shape(shape) => {
+                let mut args = vec!["--shape".to_string()];
+                args.extend(shape.to_args());
+                args
+            }
+        }
+    }
+}
+
+/// Overlay loop rate; `Auto` lets crosshair.exe idle for static images and speed up for animations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefreshRate(pub u32);
+
+impl RefreshRate {
+    pub const AUTO: RefreshRate = RefreshRate(0);
+    pub const OPTIONS: [RefreshRate; 5] = [
+        RefreshRate::AUTO,
+        RefreshRate(10),
+        RefreshRate(30),
+        RefreshRate(60),
+        RefreshRate(144),
+    ];
+}
+
+impl std::fmt::Display for RefreshRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if *self == RefreshRate::AUTO {
+            write!(f, "Auto")
+        } else {
+            write!(f, "{} Hz", self.0)
+        }
+    }
+}
+
+/// How often the topmost loop re-asserts HWND_TOPMOST
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopmostInterval(pub u32);
+
+impl TopmostInterval {
+    pub const DEFAULT: TopmostInterval = TopmostInterval(100);
+    pub const OPTIONS: [TopmostInterval; 5] = [
+        TopmostInterval(50),
+        TopmostInterval::DEFAULT,
+        TopmostInterval(250),
+        TopmostInterval(500),
+        TopmostInterval(1000),
+    ];
+}
+
+impl std::fmt::Display for TopmostInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ms", self.0)
+    }
+}
+
+/// How the crosshair process runs its loop
+#[derive(Debug, Clone)]
+pub struct OverlayOptions {
+    pub refresh: RefreshRate,
+    /// Re-assert topmost on a timer; survives fullscreen games but fights other topmost windows
+    pub aggressive_topmost: bool,
+    pub topmost_interval: TopmostInterval,
+    /// Re-assert topmost whenever the foreground window changes
+    pub topmost_on_foreground: bool,
+    /// Crosshair size relative to the image, in percent
+    pub scale_percent: u32,
+    pub opacity_percent: u32,
+    /// Only show the crosshair while this process (the game) has the foreground window
+    pub focus_process: Option<String>,
+}
+
+impl Default for OverlayOptions {
+    fn default() -> Self {
+        OverlayOptions {
+            refresh: RefreshRate::AUTO,
+            aggressive_topmost: true,
+            topmost_interval: TopmostInterval::DEFAULT,
+            topmost_on_foreground: false,
+            scale_percent: 100,
+            opacity_percent: 100,
+            focus_process: None,
+        }
+    }
+}
+
+impl OverlayOptions {
+    /// Leading crosshair.exe flags; placed first so the positional arguments stay as they were
+    fn to_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.refresh != RefreshRate::AUTO {
+            args.push("--refresh-hz".to_string());
+            args.push(self.refresh.0.to_string());
+        }
+        if !self.aggressive_topmost {
+            args.push("--no-topmost-loop".to_string());
+        } else if self.topmost_interval != TopmostInterval::DEFAULT {
+            args.push("--topmost-interval".to_string());
+            args.push(self.topmost_interval.0.to_string());
+        }
+        if self.topmost_on_foreground {
+            args.push("--topmost-on-foreground".to_string());
+        }
+        if self.scale_percent != 100 {
+            args.push("--scale".to_string());
+            args.push(self.scale_percent.to_string());
+        }
+        if self.opacity_percent != 100 {
+            args.push("--opacity".to_string());
+            args.push(self.opacity_percent.to_string());
+        }
+        if let Some(ref process) = self.focus_process {
+            args.push("--focus-process".to_string());
+            args.push(process.clone());
+        }
+        args
+    }
+}
+
+/// Start crosshair as a completely separate process, replacing any running crosshairs
+/// The crosshair watches this process and exits with it (including on a crash)
 pub fn start_overlay(
-    image_path: String,
+    source: CrosshairSource,
+    x_offset: i32,
+    y_offset: i32,
+    options: OverlayOptions,
+) -> Result<OverlayHandle, String> {
+    kill_all_crosshairs();
+    add_overlay(source, x_offset, y_offset, options)
+}
+
+/// Start one more crosshair process next to the running ones (extra crosshair layers)
+pub fn add_overlay(
+    source: CrosshairSource,
     x_offset: i32,
     y_offset: i32,
+    options: OverlayOptions,
 ) -> Result<OverlayHandle, String> {
-    // Validate image exists
-    if !Path::new(&image_path).exists() {
-        return Err(format!("Image not found: {}", image_path));
+    // Validate image exists (embedded builtin: images always do)
+    if let CrosshairSource::Image(ref image_path) = source {
+        if !assets::is_builtin(image_path) && !Path::new(image_path).exists() {
+            return Err(format!("Image not found: {}", image_path));
+        }
     }
     
     // Find the crosshair executable (should be next to the main exe)
     let crosshair_exe = get_crosshair_exe_path()?;
     
     println!("[Crosshair] Starting separate process: {}", crosshair_exe.display());
-    println!("[Crosshair] Image: {}, Offset: ({}, {})", image_path, x_offset, y_offset);
+    println!("[Crosshair] Source: {:?}, Offset: ({}, {}), Options: {:?}", source, x_offset, y_offset, options);
     
-    // Kill any existing crosshair process first
-    #[cfg(windows)]
-    {
-        let _ = Command::new("taskkill")
-            .args(["/F", "/IM", "crosshair.exe"])
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
-            .status();
-    }
+    let args = overlay_args(&source, x_offset, y_offset, &options);
     
     // Spawn crosshair as detached process
     #[cfg(windows)]
-    {
+    let child = {
         use std::os::windows::process::CommandExt;
         const DETACHED_PROCESS: u32 = 0x00000008;
         const CREATE_NO_WINDOW: u32 = 0x08000000;
         
         Command::new(&crosshair_exe)
-            .arg(&image_path)
-            .arg(x_offset.to_string())
-            .arg(y_offset.to_string())
+            .args(&args)
             .creation_flags(DETACHED_PROCESS | CREATE_NO_WINDOW)
             .stdout(Stdio::null())
             .stderr(Stdio::null())
             .spawn()
-            .map_err(|e| format!("Failed to spawn crosshair process: {}", e))?;
-    }
+            .map_err(|e| format!("Failed to spawn crosshair process: {}", e))?
+    };
     
     #[cfg(not(windows))]
-    {
-        Command::new(&crosshair_exe)
-            .arg(&image_path)
-            .arg(x_offset.to_string())
-            .arg(y_offset.to_string())
+    let child = Command::new(&crosshair_exe)
+        .args(&args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn crosshair process: {}", e))?;
+    
+    println!("[Crosshair] Process {} started successfully!", child.id());
+    
+    Ok(OverlayHandle { pid: child.id() })
+}
+
+/// Full crosshair.exe command line for a crosshair
+fn overlay_args(source: &CrosshairSource, x_offset: i32, y_offset: i32, options: &OverlayOptions) -> Vec<String> {
+    // Tie the crosshair to this process so a crash can't leave a ghost crosshair behind
+    let mut args = vec!["--parent-pid".to_string(), std::process::id().to_string()];
+    args.extend(options.to_args());
+    args.extend(source.to_args());
+    args.push(x_offset.to_string());
+    args.push(y_offset.to_string());
+    args
+}
+
+/// Loop timing of a crosshair process, from its per-tick gaps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopStats {
+    pub ticks: usize,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+impl LoopStats {
+    /// Summarize tick gaps given in microseconds (None when there are none)
+    pub fn from_gaps_us(gaps_us: &[u64]) -> Option<LoopStats> {
+        let mut sorted = gaps_us.to_vec();
+        sorted.sort_unstable();
+        let max = *sorted.last()?;
+        let percentile = |p: usize| sorted[(sorted.len() - 1) * p / 100] as f64 / 1000.0;
+        Some(LoopStats {
+            ticks: sorted.len(),
+            mean_ms: sorted.iter().sum::<u64>() as f64 / sorted.len() as f64 / 1000.0,
+            p50_ms: percentile(50),
+            p99_ms: percentile(99),
+            max_ms: max as f64 / 1000.0,
+        })
+    }
+}
+
+impl std::fmt::Display for LoopStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ticks, mean {:.1} ms, p50 {:.1} ms, p99 {:.1} ms, max {:.1} ms",
+            self.ticks, self.mean_ms, self.p50_ms, self.p99_ms, self.max_ms
+        )
+    }
+}
+
+/// How long each self-test crosshair runs before exiting on its own
+const SELF_TEST_RUN_MS: u32 = 3000;
+
+/// --overlay-selftest: start the built-in crosshair a few ways and print how long the window
+/// takes to appear and how steady crosshair.exe's loop is
+#[cfg(windows)]
+pub fn run_self_test() -> Result<(), String> {
+    use std::time::{Duration, Instant};
+    use windows::Win32::UI::WindowsAndMessaging::IsWindowVisible;
+    
+    let crosshair_exe = get_crosshair_exe_path()?;
+    println!("Overlay self-test ({})", crosshair_exe.display());
+    kill_all_crosshairs();
+    
+    let source = CrosshairSource::Image(assets::BUILTIN_DEFAULT_CROSSHAIR.to_string());
+    let runs = [
+        ("static, auto refresh", OverlayOptions::default()),
+        ("60 Hz", OverlayOptions { refresh: RefreshRate(60), ..OverlayOptions::default() }),
+        ("no topmost loop", OverlayOptions { aggressive_topmost: false, ..OverlayOptions::default() }),
+    ];
+    
+    for (label, options) in runs {
+        let log_path = std::env::temp_dir().join(format!("crosshair-selftest-{}.txt", std::process::id()));
+        let mut args = vec![
+            "--selftest".to_string(),
+            log_path.display().to_string(),
+            SELF_TEST_RUN_MS.to_string(),
+        ];
+        args.extend(overlay_args(&source, 0, 0, &options));
+        
+        let started = Instant::now();
+        let mut child = Command::new(&crosshair_exe)
+            .args(&args)
             .stdout(Stdio::null())
             .stderr(Stdio::null())
             .spawn()
             .map_err(|e| format!("Failed to spawn crosshair process: {}", e))?;
+        
+        let mut visible_after = None;
+        while started.elapsed() < Duration::from_secs(5) {
+            let visible = find_overlay_window(child.id())
+                .is_some_and(|hwnd| unsafe { IsWindowVisible(hwnd) }.as_bool());
+            if visible {
+                visible_after = Some(started.elapsed());
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        
+        let _ = child.wait();
+        let gaps_us: Vec<u64> = std::fs::read_to_string(&log_path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| line.trim().parse().ok())
+            .collect();
+        let _ = std::fs::remove_file(&log_path);
+        
+        let visible = match visible_after {
+            Some(elapsed) => format!("visible after {:.1} ms", elapsed.as_secs_f64() * 1000.0),
+            None => "never became visible".to_string(),
+        };
+        let timing = match LoopStats::from_gaps_us(&gaps_us) {
+            Some(stats) => stats.to_string(),
+            None => "no loop timing recorded".to_string(),
+        };
+        println!("[{}] {} | loop: {}", label, visible, timing);
     }
     
-    println!("[Crosshair] Process started successfully!");
-    
-    Ok(OverlayHandle {
-        process_name: "crosshair.exe".to_string(),
-    })
+    Ok(())
+}
+
+#[cfg(not(windows))]
+pub fn run_self_test() -> Result<(), String> {
+    Err("The overlay self-test only runs on Windows".to_string())
 }
 
 /// Kill all running crosshair processes (can be called without a handle)
@@ -142,3 +479,22 @@ fn get_crosshair_exe_path() -> Result<std::path::PathBuf, String> {
     
     Err("crosshair.exe not found. Make sure it's in the same directory as the main app.".to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loop_stats() {
+        assert!(LoopStats::from_gaps_us(&[]).is_none());
+
+        let mut gaps = vec![16_000; 99];
+        gaps.push(50_000);
+        let stats = LoopStats::from_gaps_us(&gaps).unwrap();
+        assert_eq!(stats.ticks, 100);
+        assert_eq!(stats.p50_ms, 16.0);
+        assert_eq!(stats.p99_ms, 16.0);
+        assert_eq!(stats.max_ms, 50.0);
+        assert!((stats.mean_ms - 16.34).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,227 @@
+//! Procedural crosshair shapes - drawn from profile settings, no image file needed
+//! Shared by the GUI (preview) and crosshair.exe (overlay rendering)
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Shape of a procedural crosshair
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CrosshairKind {
+    Dot,
+    #[default]
+    Cross,
+    Circle,
+}
+
+impl CrosshairKind {
+    pub const ALL: [CrosshairKind; 3] = [CrosshairKind::Dot, CrosshairKind::Cross, CrosshairKind::Circle];
+
+    /// Name used on the crosshair.exe command line
+    pub fn as_arg(&self) -> &'static str {
+        match self {
+            CrosshairKind::Dot => "dot",
+            CrosshairKind::Cross => "cross",
+            CrosshairKind::Circle => "circle",
+        }
+    }
+
+    /// Parse a command line name back into a kind
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        CrosshairKind::ALL.into_iter().find(|kind| kind.as_arg() == arg)
+    }
+}
+
+impl fmt::Display for CrosshairKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CrosshairKind::Dot => "Dot",
+            CrosshairKind::Cross => "Cross",
+            CrosshairKind::Circle => "Circle",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Named colors offered in the editor (0xRRGGBB)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorPreset {
+    pub name: &'static str,
+    pub rgb: u32,
+}
+
+impl fmt::Display for ColorPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+pub const COLOR_PRESETS: &[ColorPreset] = &[
+    ColorPreset { name: "Green", rgb: 0x00FF00 },
+    ColorPreset { name: "Red", rgb: 0xFF0000 },
+    ColorPreset { name: "Cyan", rgb: 0x00FFFF },
+    ColorPreset { name: "Yellow", rgb: 0xFFFF00 },
+    ColorPreset { name: "Magenta", rgb: 0xFF00FF },
+    ColorPreset { name: "White", rgb: 0xFFFFFF },
+];
+
+pub const DEFAULT_SIZE: u32 = 20;
+pub const DEFAULT_THICKNESS: u32 = 2;
+pub const DEFAULT_COLOR: u32 = 0x00FF00;
+pub const DEFAULT_GAP: u32 = 0;
+
+/// Parameters for drawing a procedural crosshair
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrosshairShape {
+    pub kind: CrosshairKind,
+    /// Width/height of the shape in pixels
+    pub size: u32,
+    /// Line thickness in pixels (unused for Dot)
+    pub thickness: u32,
+    /// Color as 0xRRGGBB
+    pub color: u32,
+    /// Empty pixels left at the center of a Cross (unused for Dot/Circle)
+    pub gap: u32,
+}
+
+impl CrosshairShape {
+    /// Render into a size x size RGBA8 buffer (straight alpha)
+    pub fn render_rgba(&self) -> (Vec<u8>, u32, u32) {
+        let size = self.size.max(1);
+        let thickness = self.thickness.clamp(1, size) as f32;
+        let center = size as f32 / 2.0;
+        let radius = size as f32 / 2.0;
+        let half_gap = self.gap as f32 / 2.0;
+
+        let r = ((self.color >> 16) & 0xFF) as u8;
+        let g = ((self.color >> 8) & 0xFF) as u8;
+        let b = (self.color & 0xFF) as u8;
+
+        let mut pixels = vec![0u8; (size * size * 4) as usize];
+
+        for y in 0..size {
+            for x in 0..size {
+                // Distance of the pixel center from the shape center
+                let dx = x as f32 + 0.5 - center;
+                let dy = y as f32 + 0.5 - center;
+
+                let covered = match self.kind {
+                    CrosshairKind::Dot => dx * dx + dy * dy <= radius * radius,
+                    CrosshairKind::Cross => {
+                        let on_bar = dx.abs() <= thickness / 2.0 || dy.abs() <= thickness / 2.0;
+                        // Gap is a square cut out of the middle, same on all four arms
+                        on_bar && dx.abs().max(dy.abs()) > half_gap
+                    }
+                    CrosshairKind::Circle => {
+                        let dist = (dx * dx + dy * dy).sqrt();
+                        dist <= radius && dist >= radius - thickness
+                    }
+                };
+
+                if covered {
+                    let idx = ((y * size + x) * 4) as usize;
+                    pixels[idx..idx + 4].copy_from_slice(&[r, g, b, 255]);
+                }
+            }
+        }
+
+        (pixels, size, size)
+    }
+
+    /// Arguments passed to crosshair.exe after `--shape`
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            self.kind.as_arg().to_string(),
+            self.size.to_string(),
+            self.thickness.to_string(),
+            format!("{:06X}", self.color),
+            self.gap.to_string(),
+        ]
+    }
+
+    /// Parse the arguments produced by `to_args`
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        if args.len() < 5 {
+            return None;
+        }
+
+        Some(CrosshairShape {
+            kind: CrosshairKind::from_arg(&args[0])?,
+            size: args[1].parse().ok()?,
+            thickness: args[2].parse().ok()?,
+            color: u32::from_str_radix(&args[3], 16).ok()?,
+            gap: args[4].parse().ok()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alpha_at(pixels: &[u8], size: u32, x: u32, y: u32) -> u8 {
+        pixels[((y * size + x) * 4 + 3) as usize]
+    }
+
+    #[test]
+    fn test_args_round_trip() {
+        let shape = CrosshairShape {
+            kind: CrosshairKind::Circle,
+            size: 24,
+            thickness: 3,
+            color: 0xFF00FF,
+            gap: 4,
+        };
+        assert_eq!(CrosshairShape::from_args(&shape.to_args()), Some(shape));
+        assert_eq!(CrosshairShape::from_args(&["bogus".to_string()]), None);
+    }
+
+    #[test]
+    fn test_cross_covers_center_not_corners() {
+        let shape = CrosshairShape {
+            kind: CrosshairKind::Cross,
+            size: 20,
+            thickness: 2,
+            color: DEFAULT_COLOR,
+            gap: DEFAULT_GAP,
+        };
+        let (pixels, w, h) = shape.render_rgba();
+        assert_eq!((w, h), (20, 20));
+        assert_eq!(alpha_at(&pixels, w, 10, 10), 255);
+        assert_eq!(alpha_at(&pixels, w, 10, 0), 255);
+        assert_eq!(alpha_at(&pixels, w, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_cross_gap_is_symmetric() {
+        let shape = CrosshairShape {
+            kind: CrosshairKind::Cross,
+            size: 20,
+            thickness: 2,
+            color: DEFAULT_COLOR,
+            gap: 6,
+        };
+        let (pixels, w, _) = shape.render_rgba();
+        // Center cut out, arms start 3px from the center on every side
+        assert_eq!(alpha_at(&pixels, w, 10, 10), 0);
+        assert_eq!(alpha_at(&pixels, w, 12, 10), 0);
+        assert_eq!(alpha_at(&pixels, w, 7, 10), 0);
+        assert_eq!(alpha_at(&pixels, w, 13, 10), 255);
+        assert_eq!(alpha_at(&pixels, w, 6, 10), 255);
+        assert_eq!(alpha_at(&pixels, w, 10, 13), 255);
+        assert_eq!(alpha_at(&pixels, w, 10, 6), 255);
+    }
+
+    #[test]
+    fn test_circle_is_hollow() {
+        let shape = CrosshairShape {
+            kind: CrosshairKind::Circle,
+            size: 20,
+            thickness: 2,
+            color: DEFAULT_COLOR,
+            gap: DEFAULT_GAP,
+        };
+        let (pixels, w, _) = shape.render_rgba();
+        assert_eq!(alpha_at(&pixels, w, 10, 10), 0);
+        assert_eq!(alpha_at(&pixels, w, 10, 0), 255);
+    }
+}
@@ -18,14 +18,19 @@ use windows::Win32::{
     UI::WindowsAndMessaging::*,
 };
 
+use crate::config::{FlyoutStyle, FLYOUT_HEADER_HEIGHT};
 use crate::ipc::TrayToGui;
 use crate::profile::Profile;
 
 const WINDOW_CLASS: &str = "TrayFlyoutWindowClass";
-const FLYOUT_WIDTH: i32 = 386;  // Match PowerToys
-const FLYOUT_HEIGHT: i32 = 486;  // Match PowerToys
-const ITEM_HEIGHT: i32 = 60;     // Taller items
-const PADDING: i32 = 16;
+const HEADER_BUTTON_SIZE: i32 = 28;
+
+/// Buttons in the flyout title row
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HeaderButton {
+    Pin,
+    Close,
+}
 
 /// Flyout window state
 pub struct FlyoutWindow {
@@ -33,8 +38,103 @@ pub struct FlyoutWindow {
     profiles: Vec<Profile>,
     active_profile: Option<String>,
     hover_index: Option<usize>,
+    /// Pixels the profile list is scrolled down by
+    scroll_offset: i32,
+    /// Typed filter; only profiles whose name contains it are shown
+    query: String,
     to_gui_tx: Sender<TrayToGui>,
     gdiplus_token: usize,
+    /// Drawn over a DWM acrylic backdrop (Windows 11) instead of as a layered window
+    backdrop: bool,
+    /// Pinned flyouts stay open when they lose focus (session only)
+    pinned: bool,
+    hover_button: Option<HeaderButton>,
+    style: FlyoutStyle,
+}
+
+/// Layout derived from the configured size (the header moves down with the padding)
+impl FlyoutStyle {
+    fn separator_y(&self) -> i32 {
+        self.padding + 34
+    }
+
+    fn subtitle_y(&self) -> i32 {
+        self.padding + 40
+    }
+
+    fn items_start_y(&self) -> i32 {
+        self.padding + FLYOUT_HEADER_HEIGHT
+    }
+
+    fn items_end_y(&self) -> i32 {
+        self.height - self.padding
+    }
+
+    fn header_button_y(&self) -> i32 {
+        self.padding + 2
+    }
+
+    fn close_button_x(&self) -> i32 {
+        self.width - self.padding - HEADER_BUTTON_SIZE
+    }
+
+    fn pin_button_x(&self) -> i32 {
+        self.close_button_x() - HEADER_BUTTON_SIZE - 4
+    }
+}
+
+/// Screen edge the taskbar is docked to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TaskbarEdge {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+/// Find the taskbar's edge and rectangle (assumes a bottom taskbar if the shell won't say)
+unsafe fn taskbar_placement() -> Option<(TaskbarEdge, RECT)> {
+    use windows::Win32::UI::Shell::{SHAppBarMessage, ABE_LEFT, ABE_RIGHT, ABE_TOP, ABM_GETTASKBARPOS, APPBARDATA};
+    
+    let mut data = APPBARDATA {
+        cbSize: mem::size_of::<APPBARDATA>() as u32,
+        ..Default::default()
+    };
+    if SHAppBarMessage(ABM_GETTASKBARPOS, &mut data) == 0 {
+        return None;
+    }
+    let edge = match data.uEdge {
+        ABE_LEFT => TaskbarEdge::Left,
+        ABE_TOP => TaskbarEdge::Top,
+        ABE_RIGHT => TaskbarEdge::Right,
+        _ => TaskbarEdge::Bottom,
+    };
+    Some((edge, data.rc))
+}
+
+/// Top-left corner for the flyout: next to the taskbar, in the corner where the tray is,
+/// and always inside the monitor's work area (which excludes a non-auto-hide taskbar)
+fn flyout_position(edge: TaskbarEdge, taskbar: RECT, work_area: RECT, width: i32, height: i32, margin: i32) -> (i32, i32) {
+    let (x, y) = match edge {
+        TaskbarEdge::Bottom => (work_area.right - width - margin, taskbar.top.min(work_area.bottom) - height - margin),
+        TaskbarEdge::Top => (work_area.right - width - margin, taskbar.bottom.max(work_area.top) + margin),
+        TaskbarEdge::Left => (taskbar.right.max(work_area.left) + margin, work_area.bottom - height - margin),
+        TaskbarEdge::Right => (taskbar.left.min(work_area.right) - width - margin, work_area.bottom - height - margin),
+    };
+    (
+        x.clamp(work_area.left, (work_area.right - width).max(work_area.left)),
+        y.clamp(work_area.top, (work_area.bottom - height).max(work_area.top)),
+    )
+}
+
+/// Index of the profile row under client coordinates `(x, y)` when the list is scrolled
+/// down by `scroll` pixels; `None` outside the list area or past the last profile
+fn hit_test(style: &FlyoutStyle, x: i32, y: i32, scroll: i32, profile_count: usize) -> Option<usize> {
+    if y < style.items_start_y() || y >= style.items_end_y() || x < style.padding || x >= style.width - style.padding {
+        return None;
+    }
+    let index = ((y - style.items_start_y() + scroll) / style.item_height) as usize;
+    (index < profile_count).then_some(index)
 }
 
 /// Menu item for rendering
@@ -47,10 +147,12 @@ struct MenuItem {
 impl FlyoutWindow {
     /// Create and show the flyout window near the tray icon
     pub fn new(
-        _tray_rect: RECT,
+        tray_rect: RECT,
         profiles: Vec<Profile>,
         active_profile: Option<String>,
         to_gui_tx: Sender<TrayToGui>,
+        use_backdrop: bool,
+        style: FlyoutStyle,
     ) -> anyhow::Result<Self> {
         unsafe {
             // Initialize GDI+
@@ -88,30 +190,54 @@ impl FlyoutWindow {
 
             RegisterClassExW(&wc);
 
-            // Use fixed dimensions like PowerToys
-            let window_height = FLYOUT_HEIGHT;
+            let window_height = style.height;
 
-            // Calculate position - appear above the tray icon in bottom-right
-            let screen_width = GetSystemMetrics(SM_CXSCREEN);
-            let screen_height = GetSystemMetrics(SM_CYSCREEN);
+            // Work area of the monitor the tray icon is on
+            let tray_center = POINT {
+                x: (tray_rect.left + tray_rect.right) / 2,
+                y: (tray_rect.top + tray_rect.bottom) / 2,
+            };
+            let monitor = MonitorFromPoint(tray_center, MONITOR_DEFAULTTONEAREST);
+            let mut monitor_info = MONITORINFO {
+                cbSize: mem::size_of::<MONITORINFO>() as u32,
+                ..Default::default()
+            };
+            let work_area = if GetMonitorInfoW(monitor, &mut monitor_info).as_bool() {
+                monitor_info.rcWork
+            } else {
+                RECT {
+                    left: 0,
+                    top: 0,
+                    right: GetSystemMetrics(SM_CXSCREEN),
+                    bottom: GetSystemMetrics(SM_CYSCREEN),
+                }
+            };
             
-            // Position: right side of screen, above taskbar (like PowerToys)
+            // Anchor next to the taskbar like PowerToys, whichever edge it is on
             let margin = 12; // PowerToys uses 12px margin
-            let final_x = screen_width - FLYOUT_WIDTH - margin;
-            let final_y = screen_height - window_height - 60; // 60px above bottom (for taskbar)
+            let (edge, taskbar) = taskbar_placement().unwrap_or((
+                TaskbarEdge::Bottom,
+                RECT { top: work_area.bottom, ..work_area },
+            ));
+            let (final_x, final_y) = flyout_position(edge, taskbar, work_area, style.width, window_height, margin);
             
-            println!("[FLYOUT] Screen: {}x{}, Position: ({}, {}), Size: {}x{}", 
-                screen_width, screen_height, final_x, final_y, FLYOUT_WIDTH, window_height);
+            println!("[FLYOUT] Taskbar: {:?}, Work area: {:?}, Position: ({}, {}), Size: {}x{}", 
+                edge, work_area, final_x, final_y, style.width, window_height);
 
-            // Create layered window at the correct position
+            // Layered unless we'll try the DWM backdrop, which only applies to regular windows
+            let ex_style = if use_backdrop {
+                WS_EX_TOPMOST | WS_EX_TOOLWINDOW
+            } else {
+                WS_EX_LAYERED | WS_EX_TOPMOST | WS_EX_TOOLWINDOW
+            };
             let hwnd = CreateWindowExW(
-                WS_EX_LAYERED | WS_EX_TOPMOST | WS_EX_TOOLWINDOW,
+                ex_style,
                 PCWSTR(class_name.as_ptr()),
                 PCWSTR::null(),
                 WS_POPUP,
                 final_x,
                 final_y,
-                FLYOUT_WIDTH,
+                style.width,
                 window_height,
                 HWND::default(),
                 HMENU::default(),
@@ -131,14 +257,28 @@ impl FlyoutWindow {
                 &policy as *const _ as *const _,
                 mem::size_of::<DWMNCRENDERINGPOLICY>() as u32,
             )?;
+            
+            // Windows 10 rejects the backdrop attribute - fall back to the layered GDI+ fill
+            let backdrop = use_backdrop && Self::apply_backdrop(hwnd);
+            if use_backdrop && !backdrop {
+                println!("[FLYOUT] System backdrop unavailable, using layered window");
+                let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE);
+                SetWindowLongPtrW(hwnd, GWL_EXSTYLE, ex_style | WS_EX_LAYERED.0 as isize);
+            }
 
             let flyout = Self {
                 hwnd,
                 profiles,
                 active_profile,
                 hover_index: None,
+                scroll_offset: 0,
+                query: String::new(),
                 to_gui_tx,
                 gdiplus_token,
+                backdrop,
+                pinned: false,
+                hover_button: None,
+                style,
             };
 
             // Store pointer to flyout in window data
@@ -156,18 +296,181 @@ impl FlyoutWindow {
         }
     }
 
+    /// Apply the Windows 11 acrylic backdrop, rounded corners and a subtle border
+    /// Returns false where the attributes aren't supported (Windows 10 and older builds)
+    unsafe fn apply_backdrop(hwnd: HWND) -> bool {
+        let backdrop = DWMSBT_TRANSIENTWINDOW;
+        if DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_SYSTEMBACKDROP_TYPE,
+            &backdrop as *const _ as *const _,
+            mem::size_of::<DWM_SYSTEMBACKDROP_TYPE>() as u32,
+        )
+        .is_err()
+        {
+            return false;
+        }
+        
+        // The backdrop shows through wherever the client area isn't painted opaque
+        let margins = windows::Win32::UI::Controls::MARGINS {
+            cxLeftWidth: -1,
+            cxRightWidth: -1,
+            cyTopHeight: -1,
+            cyBottomHeight: -1,
+        };
+        let _ = DwmExtendFrameIntoClientArea(hwnd, &margins);
+        
+        let corners = DWMWCP_ROUND;
+        let _ = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_WINDOW_CORNER_PREFERENCE,
+            &corners as *const _ as *const _,
+            mem::size_of::<DWM_WINDOW_CORNER_PREFERENCE>() as u32,
+        );
+        
+        let border = COLORREF(0x00_50_50_50); // 0x00BBGGRR
+        let _ = DwmSetWindowAttribute(
+            hwnd,
+            DWMWA_BORDER_COLOR,
+            &border as *const _ as *const _,
+            mem::size_of::<COLORREF>() as u32,
+        );
+        
+        true
+    }
+
+    /// Profiles matching the typed query (case-insensitive substring)
+    fn visible_profiles(&self) -> Vec<&Profile> {
+        let query = self.query.to_lowercase();
+        self.profiles
+            .iter()
+            .filter(|p| p.name.to_lowercase().contains(&query))
+            .collect()
+    }
+
+    /// Query changed - jump back to the top of the filtered list and redraw
+    unsafe fn set_query(&mut self, query: String) {
+        self.query = query;
+        self.scroll_offset = 0;
+        self.hover_index = None;
+        let _ = self.render();
+    }
+
+    /// Activate the hovered profile, or the first match, and close
+    unsafe fn activate_selection(&self) {
+        let visible = self.visible_profiles();
+        if let Some(profile) = visible.get(self.hover_index.unwrap_or(0)) {
+            println!("[FLYOUT] Activating profile: {}", profile.name);
+            let _ = self.to_gui_tx.send(TrayToGui::ActivateProfile(profile.name.clone()));
+            let _ = PostMessageW(self.hwnd, WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+    }
+
+    /// How far the profile list can scroll (0 when everything fits)
+    fn max_scroll(&self) -> i32 {
+        let content_height = self.visible_profiles().len() as i32 * self.style.item_height;
+        (content_height - (self.style.items_end_y() - self.style.items_start_y())).max(0)
+    }
+
+    /// Title row button under the given client coordinates
+    fn header_button_at(&self, x: i32, y: i32) -> Option<HeaderButton> {
+        let style = &self.style;
+        if y < style.header_button_y() || y >= style.header_button_y() + HEADER_BUTTON_SIZE {
+            return None;
+        }
+        if (style.pin_button_x()..style.pin_button_x() + HEADER_BUTTON_SIZE).contains(&x) {
+            Some(HeaderButton::Pin)
+        } else if (style.close_button_x()..style.close_button_x() + HEADER_BUTTON_SIZE).contains(&x) {
+            Some(HeaderButton::Close)
+        } else {
+            None
+        }
+    }
+
+    /// Pin and close buttons (Segoe MDL2 Assets glyphs)
+    unsafe fn draw_header_buttons(&self, graphics: *mut GpGraphics) {
+        let icon_family_name = "Segoe MDL2 Assets\0".encode_utf16().collect::<Vec<u16>>();
+        let mut icon_family: *mut GpFontFamily = null_mut();
+        GdipCreateFontFamilyFromName(PCWSTR(icon_family_name.as_ptr()), null_mut(), &mut icon_family);
+        let mut icon_font: *mut GpFont = null_mut();
+        GdipCreateFont(icon_family, 12.0, FontStyle(0).0, Unit(2), &mut icon_font);
+        
+        let mut centered: *mut GpStringFormat = null_mut();
+        GdipCreateStringFormat(0, 0, &mut centered);
+        GdipSetStringFormatAlign(centered, StringAlignmentCenter);
+        GdipSetStringFormatLineAlign(centered, StringAlignmentCenter);
+        
+        // Pin (E718) / Pinned (E840), Close (E8BB)
+        let pin_glyph = if self.pinned { "\u{E840}" } else { "\u{E718}" };
+        let style = &self.style;
+        for (button, x, glyph) in [
+            (HeaderButton::Pin, style.pin_button_x(), pin_glyph),
+            (HeaderButton::Close, style.close_button_x(), "\u{E8BB}"),
+        ] {
+            let highlighted = self.hover_button == Some(button) || (button == HeaderButton::Pin && self.pinned);
+            if highlighted {
+                let mut brush_hover: *mut GpSolidFill = null_mut();
+                let color = if button == HeaderButton::Pin && self.pinned { 0x60_4C_AF_50 } else { 0x40_FF_FF_FF };
+                GdipCreateSolidFill(color, &mut brush_hover);
+                let mut button_path: *mut GpPath = null_mut();
+                GdipCreatePath(FillModeWinding, &mut button_path);
+                Self::add_rounded_rectangle(
+                    button_path,
+                    x as f32,
+                    style.header_button_y() as f32,
+                    HEADER_BUTTON_SIZE as f32,
+                    HEADER_BUTTON_SIZE as f32,
+                    4.0,
+                );
+                GdipFillPath(graphics, brush_hover as *mut GpBrush, button_path);
+                GdipDeletePath(button_path);
+                GdipDeleteBrush(brush_hover as *mut GpBrush);
+            }
+            
+            let mut brush_icon: *mut GpSolidFill = null_mut();
+            GdipCreateSolidFill(0xFF_FF_FF_FF, &mut brush_icon);
+            let text = glyph.encode_utf16().collect::<Vec<u16>>();
+            let rect = RectF {
+                X: x as f32,
+                Y: style.header_button_y() as f32,
+                Width: HEADER_BUTTON_SIZE as f32,
+                Height: HEADER_BUTTON_SIZE as f32,
+            };
+            GdipDrawString(
+                graphics,
+                PCWSTR(text.as_ptr()),
+                text.len() as i32,
+                icon_font,
+                &rect,
+                centered,
+                brush_icon as *mut GpBrush,
+            );
+            GdipDeleteBrush(brush_icon as *mut GpBrush);
+        }
+        
+        GdipDeleteStringFormat(centered);
+        GdipDeleteFont(icon_font);
+        GdipDeleteFontFamily(icon_family);
+    }
+
+    /// Profile under the given client coordinates, accounting for scroll
+    fn item_at(&self, x: i32, y: i32) -> Option<usize> {
+        hit_test(&self.style, x, y, self.scroll_offset, self.visible_profiles().len())
+    }
+
     /// Render the flyout menu with GDI+
     unsafe fn render(&self) -> anyhow::Result<()> {
         let screen_dc = GetDC(None);
         let mem_dc = CreateCompatibleDC(screen_dc);
 
-        let window_height = FLYOUT_HEIGHT;
+        let style = &self.style;
+        let window_height = style.height;
 
         // Create DIB for layered window
         let bmi = BITMAPINFO {
             bmiHeader: BITMAPINFOHEADER {
                 biSize: mem::size_of::<BITMAPINFOHEADER>() as u32,
-                biWidth: FLYOUT_WIDTH,
+                biWidth: style.width,
                 biHeight: -window_height, // Top-down
                 biPlanes: 1,
                 biBitCount: 32,
@@ -202,30 +505,33 @@ impl FlyoutWindow {
         GdipSetSmoothingMode(graphics, SmoothingMode(4)); // SmoothingModeAntiAlias
         GdipSetTextRenderingHint(graphics, TextRenderingHint(5)); // TextRenderingHintClearTypeGridFit
 
-        // Clear with semi-transparent dark background
+        // Clear with semi-transparent dark background (just a light tint over the backdrop)
         let mut brush_bg: *mut GpSolidFill = null_mut();
-        GdipCreateSolidFill(0xF0_1E_1E_1E, &mut brush_bg); // ARGB
+        let bg_color = if self.backdrop { 0x60_1E_1E_1E } else { 0xF0_1E_1E_1E };
+        GdipCreateSolidFill(bg_color, &mut brush_bg); // ARGB
         GdipFillRectangleI(
             graphics,
             brush_bg as *mut GpBrush,
             0,
             0,
-            FLYOUT_WIDTH,
+            style.width,
             window_height,
         );
 
-        // Draw rounded background rectangle
+        // Draw rounded background rectangle (DWM rounds the corners itself with the backdrop)
         let mut path: *mut GpPath = null_mut();
         GdipCreatePath(FillModeWinding, &mut path);
         Self::add_rounded_rectangle(
             path,
             4.0,
             4.0,
-            (FLYOUT_WIDTH - 8) as f32,
+            (style.width - 8) as f32,
             (window_height - 8) as f32,
             8.0,
         );
-        GdipFillPath(graphics, brush_bg as *mut GpBrush, path);
+        if !self.backdrop {
+            GdipFillPath(graphics, brush_bg as *mut GpBrush, path);
+        }
 
         // Create font
         let font_family_name = "Segoe UI\0".encode_utf16().collect::<Vec<u16>>();
@@ -246,9 +552,9 @@ impl FlyoutWindow {
         
         let title = "Gaming Profiles\0".encode_utf16().collect::<Vec<u16>>();
         let title_rect = RectF {
-            X: PADDING as f32,
-            Y: PADDING as f32,
-            Width: (FLYOUT_WIDTH - PADDING * 2) as f32,
+            X: style.padding as f32,
+            Y: style.padding as f32,
+            Width: (style.width - style.padding * 2) as f32,
             Height: 40.0,
         };
         
@@ -268,21 +574,28 @@ impl FlyoutWindow {
         );
         GdipDeleteBrush(brush_title as *mut GpBrush);
         
+        self.draw_header_buttons(graphics);
+        
         // Draw separator line under title
         let mut pen_sep: *mut GpPen = null_mut();
         GdipCreatePen1(0x40_FF_FF_FF, 1.0, UnitPixel, &mut pen_sep);
-        GdipDrawLineI(graphics, pen_sep, PADDING, 50, FLYOUT_WIDTH - PADDING, 50);
+        GdipDrawLineI(graphics, pen_sep, style.padding, style.separator_y(), style.width - style.padding, style.separator_y());
         GdipDeletePen(pen_sep);
         
         // Subtitle "Select a profile to activate"
         let mut brush_subtitle: *mut GpSolidFill = null_mut();
         GdipCreateSolidFill(0x80_FF_FF_FF, &mut brush_subtitle);
         
-        let subtitle = "Click to activate a profile\0".encode_utf16().collect::<Vec<u16>>();
+        let subtitle = if self.query.is_empty() {
+            "Click to activate, or type to search\0".to_string()
+        } else {
+            format!("Search: {}\0", self.query)
+        };
+        let subtitle = subtitle.encode_utf16().collect::<Vec<u16>>();
         let subtitle_rect = RectF {
-            X: PADDING as f32,
-            Y: 56.0,
-            Width: (FLYOUT_WIDTH - PADDING * 2) as f32,
+            X: style.padding as f32,
+            Y: style.subtitle_y() as f32,
+            Width: (style.width - style.padding * 2) as f32,
             Height: 24.0,
         };
         
@@ -300,12 +613,23 @@ impl FlyoutWindow {
         );
         GdipDeleteBrush(brush_subtitle as *mut GpBrush);
         
-        // Profile items start below subtitle
-        let items_start_y = 90;
+        // Clip items to the list area so partially scrolled items don't spill over the header
+        GdipSetClipRectI(
+            graphics,
+            0,
+            style.items_start_y(),
+            style.width,
+            style.items_end_y() - style.items_start_y(),
+            CombineMode(0), // CombineModeReplace
+        );
 
-        // Draw profile items
-        for (i, profile) in self.profiles.iter().enumerate() {
-            let y = items_start_y + i as i32 * ITEM_HEIGHT;
+        // Draw profile items (shifted up by the scroll offset)
+        let visible = self.visible_profiles();
+        for (i, profile) in visible.iter().enumerate() {
+            let y = style.items_start_y() + i as i32 * style.item_height - self.scroll_offset;
+            if y + style.item_height <= style.items_start_y() || y >= style.items_end_y() {
+                continue;
+            }
             let is_hover = self.hover_index == Some(i);
             let is_active = self.active_profile.as_ref() == Some(&profile.name);
 
@@ -318,10 +642,10 @@ impl FlyoutWindow {
                 GdipCreatePath(FillModeWinding, &mut hover_path);
                 Self::add_rounded_rectangle(
                     hover_path,
-                    PADDING as f32,
+                    style.padding as f32,
                     y as f32,
-                    (FLYOUT_WIDTH - PADDING * 2) as f32,
-                    (ITEM_HEIGHT - 4) as f32,
+                    (style.width - style.padding * 2) as f32,
+                    (style.item_height - 4) as f32,
                     6.0,
                 );
                 GdipFillPath(graphics, brush_hover as *mut GpBrush, hover_path);
@@ -335,9 +659,9 @@ impl FlyoutWindow {
             
             let text = profile.name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
             let rect = RectF {
-                X: (PADDING + 12) as f32,
+                X: (style.padding + 12) as f32,
                 Y: (y + 8) as f32,
-                Width: (FLYOUT_WIDTH - PADDING * 2 - 50) as f32,
+                Width: (style.width - style.padding * 2 - 50) as f32,
                 Height: 24.0,
             };
 
@@ -351,13 +675,16 @@ impl FlyoutWindow {
                 brush_text as *mut GpBrush,
             );
             
-            // Profile description (processes to kill count)
-            let desc = format!("{} processes to manage\0", profile.processes_to_kill.len());
+            // Profile description (first line of notes, else processes to kill count)
+            let desc = match profile.description_summary() {
+                Some(summary) => format!("{}\0", summary),
+                None => format!("{} processes to manage\0", profile.processes_to_kill.len()),
+            };
             let desc_utf16: Vec<u16> = desc.encode_utf16().collect();
             let desc_rect = RectF {
-                X: (PADDING + 12) as f32,
+                X: (style.padding + 12) as f32,
                 Y: (y + 30) as f32,
-                Width: (FLYOUT_WIDTH - PADDING * 2 - 50) as f32,
+                Width: (style.width - style.padding * 2 - 50) as f32,
                 Height: 20.0,
             };
             
@@ -380,8 +707,8 @@ impl FlyoutWindow {
                 let mut brush_active: *mut GpSolidFill = null_mut();
                 GdipCreateSolidFill(0xFF_4C_AF_50, &mut brush_active); // Green
                 
-                let badge_x = FLYOUT_WIDTH - PADDING - 60;
-                let badge_y = y + ITEM_HEIGHT / 2 - 10;
+                let badge_x = style.width - style.padding - 60;
+                let badge_y = y + style.item_height / 2 - 10;
                 
                 // Draw "Active" text
                 let active_text = "Active\0".encode_utf16().collect::<Vec<u16>>();
@@ -405,17 +732,48 @@ impl FlyoutWindow {
 
             GdipDeleteBrush(brush_text as *mut GpBrush);
         }
+        GdipResetClip(graphics);
+
+        // Scrollbar thumb when the list overflows
+        let max_scroll = self.max_scroll();
+        if max_scroll > 0 {
+            let view_height = style.items_end_y() - style.items_start_y();
+            let content_height = view_height + max_scroll;
+            let thumb_height = (view_height * view_height / content_height).max(24);
+            let thumb_y = style.items_start_y() + self.scroll_offset * (view_height - thumb_height) / max_scroll;
+
+            let mut brush_thumb: *mut GpSolidFill = null_mut();
+            GdipCreateSolidFill(0x60_FF_FF_FF, &mut brush_thumb);
+            let mut thumb_path: *mut GpPath = null_mut();
+            GdipCreatePath(FillModeWinding, &mut thumb_path);
+            Self::add_rounded_rectangle(
+                thumb_path,
+                (style.width - style.padding / 2 - 2) as f32,
+                thumb_y as f32,
+                4.0,
+                thumb_height as f32,
+                2.0,
+            );
+            GdipFillPath(graphics, brush_thumb as *mut GpBrush, thumb_path);
+            GdipDeletePath(thumb_path);
+            GdipDeleteBrush(brush_thumb as *mut GpBrush);
+        }
         
         // Draw "No profiles" message if empty
-        if self.profiles.is_empty() {
+        if visible.is_empty() {
             let mut brush_empty: *mut GpSolidFill = null_mut();
             GdipCreateSolidFill(0x80_FF_FF_FF, &mut brush_empty);
             
-            let empty_text = "No gaming profiles configured\0".encode_utf16().collect::<Vec<u16>>();
+            let empty_text = if self.profiles.is_empty() {
+                "No gaming profiles configured\0"
+            } else {
+                "No profiles match\0"
+            };
+            let empty_text = empty_text.encode_utf16().collect::<Vec<u16>>();
             let empty_rect = RectF {
-                X: PADDING as f32,
+                X: style.padding as f32,
                 Y: (window_height / 2 - 20) as f32,
-                Width: (FLYOUT_WIDTH - PADDING * 2) as f32,
+                Width: (style.width - style.padding * 2) as f32,
                 Height: 40.0,
             };
             
@@ -448,11 +806,24 @@ impl FlyoutWindow {
         GdipDeleteGraphics(graphics);
 
         // Premultiply alpha for layered window
-        Self::premultiply_alpha(bits as *mut u8, FLYOUT_WIDTH, window_height);
+        Self::premultiply_alpha(bits as *mut u8, style.width, window_height);
+
+        // Backdrop windows aren't layered - paint the premultiplied bitmap straight in;
+        // DWM uses its alpha to blend with the acrylic
+        if self.backdrop {
+            let window_dc = GetDC(self.hwnd);
+            let _ = BitBlt(window_dc, 0, 0, style.width, window_height, mem_dc, 0, 0, SRCCOPY);
+            ReleaseDC(self.hwnd, window_dc);
+            
+            DeleteObject(hbitmap);
+            DeleteDC(mem_dc);
+            ReleaseDC(None, screen_dc);
+            return anyhow::Ok(());
+        }
 
         // Update layered window (don't pass win_pos, use current window position)
         let win_size = SIZE {
-            cx: FLYOUT_WIDTH,
+            cx: style.width,
             cy: window_height,
         };
         let src_pos = POINT { x: 0, y: 0 };
@@ -560,20 +931,34 @@ impl FlyoutWindow {
                     let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as i32;
                     let x = (lparam.0 & 0xFFFF) as i16 as i32;
                     
-                    // Items start at y=90 (below title and subtitle)
-                    let items_start_y = 90;
-                    let item_index = (y - items_start_y) / ITEM_HEIGHT;
+                    let hover = flyout.item_at(x, y);
+                    let hover_button = flyout.header_button_at(x, y);
+                    if flyout.hover_index != hover || flyout.hover_button != hover_button {
+                        flyout.hover_index = hover;
+                        flyout.hover_button = hover_button;
+                        let _ = flyout.render();
+                    }
+                }
+                LRESULT(0)
+            }
+            WM_MOUSEWHEEL => {
+                let flyout = Self::get_flyout(hwnd);
+                if let Some(flyout) = flyout {
+                    // One wheel notch (120) scrolls one item
+                    let delta = ((wparam.0 >> 16) & 0xFFFF) as i16 as i32;
+                    let new_offset = (flyout.scroll_offset - delta * flyout.style.item_height / 120)
+                        .clamp(0, flyout.max_scroll());
                     
-                    // Check if mouse is in the item area
-                    if y >= items_start_y && x >= PADDING && x < (FLYOUT_WIDTH - PADDING) 
-                        && item_index >= 0 && (item_index as usize) < flyout.profiles.len() 
-                    {
-                        if flyout.hover_index != Some(item_index as usize) {
-                            flyout.hover_index = Some(item_index as usize);
-                            let _ = flyout.render();
-                        }
-                    } else if flyout.hover_index.is_some() {
-                        flyout.hover_index = None;
+                    if new_offset != flyout.scroll_offset {
+                        flyout.scroll_offset = new_offset;
+                        
+                        // Wheel coordinates are screen-relative; re-hit-test under the cursor
+                        let mut point = POINT {
+                            x: (lparam.0 & 0xFFFF) as i16 as i32,
+                            y: ((lparam.0 >> 16) & 0xFFFF) as i16 as i32,
+                        };
+                        ScreenToClient(hwnd, &mut point);
+                        flyout.hover_index = flyout.item_at(point.x, point.y);
                         let _ = flyout.render();
                     }
                 }
@@ -582,25 +967,77 @@ impl FlyoutWindow {
             WM_LBUTTONDOWN => {
                 let flyout = Self::get_flyout(hwnd);
                 if let Some(flyout) = flyout {
-                    if let Some(index) = flyout.hover_index {
-                        if let Some(profile) = flyout.profiles.get(index) {
-                            println!("[FLYOUT] Activating profile: {}", profile.name);
-                            // Send activation request to main app
-                            let _ = flyout.to_gui_tx.send(TrayToGui::ActivateProfile(profile.name.clone()));
-                            // Close flyout
+                    let x = (lparam.0 & 0xFFFF) as i16 as i32;
+                    let y = ((lparam.0 >> 16) & 0xFFFF) as i16 as i32;
+                    match flyout.header_button_at(x, y) {
+                        Some(HeaderButton::Pin) => {
+                            flyout.pinned = !flyout.pinned;
+                            println!("[FLYOUT] Pinned: {}", flyout.pinned);
+                            let _ = flyout.render();
+                        }
+                        Some(HeaderButton::Close) => {
+                            let _ = PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0));
+                        }
+                        None if flyout.hover_index.is_some() => flyout.activate_selection(),
+                        None => {}
+                    }
+                }
+                LRESULT(0)
+            }
+            WM_CHAR => {
+                let flyout = Self::get_flyout(hwnd);
+                if let Some(flyout) = flyout {
+                    match char::from_u32(wparam.0 as u32) {
+                        // Backspace edits the query
+                        Some('\u{8}') => {
+                            let mut query = flyout.query.clone();
+                            query.pop();
+                            flyout.set_query(query);
+                        }
+                        Some(c) if !c.is_control() => {
+                            let mut query = flyout.query.clone();
+                            query.push(c);
+                            flyout.set_query(query);
+                        }
+                        _ => {}
+                    }
+                }
+                LRESULT(0)
+            }
+            WM_KEYDOWN => {
+                let flyout = Self::get_flyout(hwnd);
+                if let Some(flyout) = flyout {
+                    match wparam.0 as u16 {
+                        // VK_ESCAPE clears the query first, then closes
+                        0x1B if !flyout.query.is_empty() => flyout.set_query(String::new()),
+                        0x1B => {
                             let _ = PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0));
                         }
+                        0x0D => flyout.activate_selection(), // VK_RETURN
+                        _ => {}
                     }
                 }
                 LRESULT(0)
             }
+            WM_PAINT => {
+                // Only backdrop windows get WM_PAINT; layered ones are drawn by render()
+                let mut ps = PAINTSTRUCT::default();
+                BeginPaint(hwnd, &mut ps);
+                if let Some(flyout) = Self::get_flyout(hwnd) {
+                    let _ = flyout.render();
+                }
+                let _ = EndPaint(hwnd, &ps);
+                LRESULT(0)
+            }
+            WM_ERASEBKGND => LRESULT(1),
             WM_KILLFOCUS => {
                 // Don't auto-close on focus loss - let user interact
                 LRESULT(0)
             }
             WM_ACTIVATE => {
-                // Close flyout when deactivated (clicked outside)
-                if wparam.0 == 0 {
+                // Close flyout when deactivated (clicked outside), unless pinned
+                let pinned = Self::get_flyout(hwnd).is_some_and(|f| f.pinned);
+                if wparam.0 == 0 && !pinned {
                     let _ = PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0));
                 }
                 LRESULT(0)
@@ -648,6 +1085,8 @@ impl FlyoutWindow {
     pub fn update_profiles(&mut self, profiles: Vec<Profile>, active: Option<String>) -> anyhow::Result<()> {
         self.profiles = profiles;
         self.active_profile = active;
+        self.scroll_offset = self.scroll_offset.min(self.max_scroll());
+        self.hover_index = None;
         unsafe { self.render() }
     }
 }
@@ -678,3 +1117,88 @@ pub fn get_tray_rect() -> RECT {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORK: RECT = RECT { left: 0, top: 0, right: 1920, bottom: 1040 };
+
+    #[test]
+    fn test_flyout_position_follows_taskbar_edge() {
+        // Bottom taskbar: bottom-right, above the taskbar
+        let taskbar = RECT { left: 0, top: 1040, right: 1920, bottom: 1080 };
+        assert_eq!(flyout_position(TaskbarEdge::Bottom, taskbar, WORK, 386, 486, 12), (1522, 542));
+
+        // Left taskbar: bottom-left, right of the taskbar
+        let work = RECT { left: 48, ..WORK };
+        let taskbar = RECT { left: 0, top: 0, right: 48, bottom: 1080 };
+        assert_eq!(flyout_position(TaskbarEdge::Left, taskbar, work, 386, 486, 12), (60, 542));
+
+        // Top taskbar: top-right, below the taskbar
+        let work = RECT { top: 40, ..WORK };
+        let taskbar = RECT { left: 0, top: 0, right: 1920, bottom: 40 };
+        assert_eq!(flyout_position(TaskbarEdge::Top, taskbar, work, 386, 486, 12), (1522, 52));
+    }
+
+    #[test]
+    fn test_flyout_position_clamped_to_work_area() {
+        // Tiny screen: never placed off the top-left
+        let work = RECT { left: 0, top: 0, right: 300, bottom: 400 };
+        let taskbar = RECT { left: 0, top: 400, right: 300, bottom: 440 };
+        assert_eq!(flyout_position(TaskbarEdge::Bottom, taskbar, work, 386, 486, 12), (0, 0));
+    }
+
+    #[test]
+    fn test_hit_test_rows() {
+        let style = FlyoutStyle::default();
+        let x = style.width / 2;
+        let (start, row) = (style.items_start_y(), style.item_height);
+        assert_eq!(hit_test(&style, x, start - 1, 0, 3), None);
+        assert_eq!(hit_test(&style, x, start, 0, 3), Some(0));
+        assert_eq!(hit_test(&style, x, start + row - 1, 0, 3), Some(0));
+        assert_eq!(hit_test(&style, x, start + row, 0, 3), Some(1));
+
+        // Empty space below the last profile
+        assert_eq!(hit_test(&style, x, start + 3 * row, 0, 3), None);
+        assert_eq!(hit_test(&style, x, start, 0, 0), None);
+    }
+
+    #[test]
+    fn test_hit_test_edges_and_scroll() {
+        let style = FlyoutStyle::default();
+        let (y, row, pad) = (style.items_start_y(), style.item_height, style.padding);
+        assert_eq!(hit_test(&style, pad - 1, y, 0, 3), None);
+        assert_eq!(hit_test(&style, pad, y, 0, 3), Some(0));
+        assert_eq!(hit_test(&style, style.width - pad - 1, y, 0, 3), Some(0));
+        assert_eq!(hit_test(&style, style.width - pad, y, 0, 3), None);
+
+        // Scrolled by one row: the top of the list is the second profile
+        assert_eq!(hit_test(&style, pad, y, row, 3), Some(1));
+        assert_eq!(hit_test(&style, pad, y + row / 2, row / 2, 3), Some(1));
+
+        // Rows scrolled past the bottom padding can't be hit
+        let rows_in_view = ((style.items_end_y() - y) / row) as usize;
+        assert_eq!(hit_test(&style, pad, style.items_end_y() - 1, 0, 20), Some(rows_in_view));
+        assert_eq!(hit_test(&style, pad, style.items_end_y(), 0, 20), None);
+    }
+
+    #[test]
+    fn test_hit_test_custom_style() {
+        let style = FlyoutStyle { width: 300, height: 400, item_height: 80, padding: 8 };
+        assert_eq!(hit_test(&style, 8, style.items_start_y() + 80, 0, 3), Some(1));
+        assert_eq!(hit_test(&style, 292, style.items_start_y(), 0, 3), None);
+        assert_eq!(hit_test(&style, 20, 392, 0, 20), None);
+    }
+
+    #[test]
+    fn test_header_follows_padding() {
+        let style = FlyoutStyle { padding: 48, ..Default::default() }.clamped();
+        // Title row (and its buttons) above the separator, subtitle above the list
+        assert!(style.header_button_y() + HEADER_BUTTON_SIZE < style.separator_y());
+        assert!(style.separator_y() < style.subtitle_y());
+        assert!(style.subtitle_y() + 24 <= style.items_start_y());
+        assert_eq!(hit_test(&style, style.width / 2, 90, 0, 3), None);
+        assert_eq!(hit_test(&style, style.width / 2, style.items_start_y(), 0, 3), Some(0));
+    }
+}
@@ -4,17 +4,27 @@ pub mod styles;
 
 use iced::{
     executor, Application, Command, Element, Settings, Length, Alignment, Theme, Subscription,
-    widget::{Container, Column, Row, Text, Button, Scrollable, Checkbox, TextInput, Space, Toggler},
+    widget::{Container, Column, Row, Text, Button, Scrollable, Checkbox, TextInput, Space, Toggler, PickList, Slider, scrollable, text_editor, text_input, TextEditor},
 };
+use iced::advanced::widget::{operation::{Focusable, Operation, Outcome}, Id as WidgetId};
 use std::collections::{HashMap, HashSet};
 use std::time::Duration;
-use crate::profile::Profile;
-use crate::common_apps::COMMON_APPS;
-use crate::config::get_data_directory;
-use crate::profile::{load_profiles, save_profiles};
-use crate::image_picker::{open_image_picker, validate_crosshair_image};
-use crate::process::{list_processes, kill_processes, ProcessInfo};
-use crate::crosshair_overlay::{self, OverlayHandle};
+use crate::profile::{CopyFields, CrosshairLayer, CrosshairPreset, Profile};
+use crate::common_apps::{app_status, AppStatus, COMMON_APPS};
+use crate::config::{get_data_directory, AppConfig};
+use crate::profile::{create_profile, load_profiles, save_profiles, is_profile_name_unique, name_with_suffix, format_relative_time, parse_tags, unix_now};
+use crate::image_picker::{open_image_picker, reveal_in_explorer, validate_crosshair_image};
+use crate::process::{kill_processes, protection_reason, restore_priorities, running_confirm_targets, set_priority, top_consumers, KillReport, PriorityClass, ProcessInfo, ProcessSampler, ProcessUsage, ProtectionReason};
+use crate::optimize;
+use crate::assets;
+use crate::audio;
+use crate::logging;
+use crate::notifications;
+use crate::sound;
+use crate::hotkey::{self, Hotkey};
+use crate::power::{self, PowerPlan, PowerPlanGuid};
+use crate::crosshair_overlay::{self, CrosshairSource, OverlayHandle, OverlayOptions, RefreshRate, TopmostInterval};
+use crate::crosshair_shapes::{self, ColorPreset, CrosshairKind, CrosshairShape, COLOR_PRESETS};
 use crate::tray_flyout::TrayFlyoutManager;
 use std::sync::Mutex;
 use std::sync::mpsc::Receiver;
@@ -37,22 +47,70 @@ static FLYOUT_PROFILE_RX: Lazy<Mutex<Option<Receiver<String>>>> = Lazy::new(|| M
 static LAST_CLICK_TIME: Lazy<Mutex<Option<Instant>>> = Lazy::new(|| Mutex::new(None));
 static PENDING_SINGLE_CLICK: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
 
+/// Audio dropdown entry that leaves the Windows default device alone
+const KEEP_AUDIO_DEVICE: &str = "Don't change";
+
+/// Power plan dropdown entry that leaves the current plan alone
+const KEEP_POWER_PLAN: &str = "Don't change";
+
+/// Activation sound dropdown entry for "no sound"
+const NO_SOUND: &str = "None";
+
+/// Tag filter entry that shows every profile
+const ALL_TAGS: &str = "All tags";
+
+/// Most rows the process picker shows; the filter narrows the rest down
+const PROCESS_LIST_LIMIT: usize = 50;
+
+/// Startup profile dropdown entry for launching with nothing active
+const NO_STARTUP_PROFILE: &str = "Nothing active";
+
 /// Store menu item IDs for checking exit
 static MENU_EXIT_ID: Lazy<Mutex<Option<tray_icon::menu::MenuId>>> = Lazy::new(|| Mutex::new(None));
+/// Scrollable on the Logs page, snapped to the bottom as new lines arrive
+static LOG_SCROLL_ID: Lazy<scrollable::Id> = Lazy::new(scrollable::Id::unique);
+/// Toggle Crosshair menu item flips the active profile's overlay
+static MENU_TOGGLE_CROSSHAIR_ID: Lazy<Mutex<Option<tray_icon::menu::MenuId>>> = Lazy::new(|| Mutex::new(None));
+/// Settings menu item re-shows a window hidden by close-to-tray
+static MENU_SETTINGS_ID: Lazy<Mutex<Option<tray_icon::menu::MenuId>>> = Lazy::new(|| Mutex::new(None));
+/// WM_HOTKEY id of the active profile's crosshair cycle shortcut
+const CYCLE_HOTKEY_ID: i32 = 1;
 
 #[derive(Debug, Clone)]
 pub enum Message {
     // Profile management
     ProfileNameChanged(String),
+    DescriptionEdited(text_editor::Action),
+    TagsChanged(String),
     ProfileSelected(usize),
     NewProfile,
     SaveProfile,
+    /// Discard unsaved editor changes and reload the selected profile
+    RevertProfile,
+    SaveAndReapply,
     DeleteProfile,
+    DuplicateProfile,
+    OpenCopySettings,
+    CloseCopySettings,
+    CopyFieldsChanged(CopyFields),
+    CopyTargetToggled(usize, bool),
+    CopySettingsTo { targets: Vec<usize>, fields: CopyFields },
+    BackupProfiles,
+    RestoreProfiles,
     ActivateProfile,
+    DeactivateProfile,
     
     // Process selection
     ProcessToggled(String, bool),
+    /// Tab / Shift+Tab outside a captured widget: move through the editor's text inputs
+    TabPressed { backwards: bool },
+    FocusEditorField(EditorField),
+    /// Add every process shown in the picker to the kill list, optionally leaving out
+    /// critical processes
+    SelectVisibleProcesses { skip_protected: bool },
     RefreshProcesses,
+    QuickScan,
+    DismissQuickScan,
     ProcessFilterChanged(String),
     
     // Crosshair settings
@@ -62,18 +120,87 @@ pub enum Message {
     CrosshairMoveDown,
     CrosshairMoveLeft,
     CrosshairMoveRight,
+    CrosshairMoveBy(i32, i32),
     CrosshairCenter,
+    CrosshairRestore,
+    ResetCrosshair,
+    PreviewKill,
+    PositioningModeToggled(bool),
     OverlayEnabledToggled(bool),
+    CrosshairKindSelected(CrosshairKind),
+    CrosshairSizeChanged(u32),
+    CrosshairThicknessChanged(u32),
+    CrosshairGapChanged(u32),
+    CrosshairColorSelected(ColorPreset),
+    CrosshairShapeCommitted,
+    CrosshairRefreshSelected(RefreshRate),
+    AggressiveTopmostToggled(bool),
+    TopmostIntervalSelected(TopmostInterval),
+    TopmostOnForegroundToggled(bool),
+    HideWhenUnfocusedToggled(bool),
+    GameProcessChanged(String),
     SelectImage,
+    UseBuiltinImage,
     ClearImage,
+    RevealCrosshairImage,
+    ImageFileHovered,
+    ImageFileHoverLeft,
+    ImageFileDropped(std::path::PathBuf),
+    AddCrosshairLayer,
+    AddCrosshairPreset,
+    UseCrosshairPreset(usize),
+    RemoveCrosshairPreset(usize),
+    CycleShortcutChanged(String),
+    RemoveCrosshairLayer(usize),
+    CrosshairLayerXChanged(usize, i32),
+    CrosshairLayerYChanged(usize, i32),
+    CrosshairLayerScaleChanged(usize, u32),
+    CrosshairLayerOpacityChanged(usize, u32),
     
     // Fan control
     FanSpeedMaxToggled(bool),
+    AudioDeviceSelected(String),
+    ActivationSoundSelected(String),
+    ChooseActivationSound,
+    TestActivationSound,
+    MuteSoundsToggled(bool),
+    PowerPlanSelected(String),
+    RefreshAudioDevices,
+    DeactivateOnExitChanged(String),
+    TrimMemoryToggled(bool),
+    PriorityNameChanged(String),
+    ProfileSortSelected(ProfileSort),
+    TagFilterSelected(String),
+    ResetUsageStats(usize),
+    PriorityClassSelected(PriorityClass),
+    AddPriorityRule,
+    RemovePriorityRule(usize),
+    
+    // Window
+    WindowCloseRequested,
+    CloseToTrayToggled(bool),
+    NotificationsToggled(bool),
+    FlyoutBackdropToggled(bool),
+    LockActiveProfileToggled(bool),
+    StartMinimizedToggled(bool),
+    StartupProfileSelected(String),
+    ReconnectTray,
     
     // Tray events
     TrayTick,
     TrayProfileSelected(String),
     TrayDeactivate,
+    TrayToggleCrosshair,
+    CycleCrosshairPreset,
+    ShowPage(Page),
+    WelcomeAppToggled(String, bool),
+    WelcomeCrosshairToggled(bool),
+    FinishWelcome,
+    LogTick,
+    FlashTick,
+    CopyLogs,
+    CopyKillReport,
+    OpenLogFolder,
     TrayExit,
 }
 
@@ -83,18 +210,68 @@ pub struct GameOptimizer {
     
     // Current editing state
     edit_name: String,
+    edit_description: text_editor::Content,
+    /// Comma-separated tags as typed
+    edit_tags: String,
     edit_x_offset: String,
     edit_y_offset: String,
     edit_image_path: Option<String>,
     edit_overlay_enabled: bool,
     edit_fan_speed_max: bool,
+    edit_audio_device: Option<String>,
+    /// Friendly names of connected output devices for the audio dropdown
+    audio_devices: Vec<String>,
+    edit_power_plan: Option<PowerPlanGuid>,
+    /// Installed power plans for the dropdown (empty if the API is unavailable)
+    power_plans: Vec<PowerPlan>,
+    /// Plan that was active before a profile switched it, restored on deactivation
+    previous_power_plan: Option<PowerPlanGuid>,
+    /// Processes the active profile re-prioritized, with the class each had before
+    priority_changes: Vec<(u32, PriorityClass)>,
+    edit_trim_memory: bool,
+    /// Process name whose exit deactivates the profile (empty = off)
+    edit_deactivate_on_exit: String,
+    edit_activation_sound: Option<String>,
+    /// Watching the active profile's process so it can deactivate when it exits
+    exit_watch: Option<ExitWatch>,
+    edit_priority_rules: Vec<(String, PriorityClass)>,
+    priority_rule_name: String,
+    priority_rule_class: PriorityClass,
+    profile_sort: ProfileSort,
+    /// Sidebar only lists profiles with this tag
+    tag_filter: Option<String>,
+    edit_crosshair_kind: CrosshairKind,
+    edit_crosshair_size: u32,
+    edit_crosshair_thickness: u32,
+    edit_crosshair_color: u32,
+    edit_crosshair_gap: u32,
+    edit_crosshair_refresh: RefreshRate,
+    edit_aggressive_topmost: bool,
+    edit_topmost_interval: TopmostInterval,
+    edit_topmost_on_foreground: bool,
+    edit_hide_when_unfocused: bool,
+    edit_game_process: String,
+    edit_crosshair_layers: Vec<CrosshairLayer>,
+    edit_crosshair_presets: Vec<CrosshairPreset>,
+    edit_cycle_shortcut: String,
     
     // Process selection (executable name -> selected)
     process_selection: HashMap<String, bool>,
     
     // Live system processes
     running_processes: Vec<ProcessInfo>,
+    process_sampler: ProcessSampler,
+    /// Running/installed state of each COMMON_APPS entry, refreshed with the process list
+    common_app_status: Vec<AppStatus>,
     process_filter: String,
+    /// Profile name and full kill report of the last activation, for "Copy report"
+    last_kill_report: Option<(String, KillReport)>,
+    /// Result of the last performance scan, shown above the process list until dismissed
+    quick_scan: Option<Vec<ProcessUsage>>,
+    /// Crosshair cycle shortcut currently registered for the active profile
+    cycle_hotkey: Option<Hotkey>,
+    /// Profile just activated and when, pulsed in the sidebar until ACTIVATION_FLASH runs out
+    activation_flash: Option<(String, Instant)>,
     
     // Status message
     status_message: String,
@@ -106,10 +283,187 @@ pub struct GameOptimizer {
     active_profile_name: Option<String>,
     
     // Crosshair overlay handle
-    overlay_handle: Option<OverlayHandle>,
+    /// Running crosshair processes: the main crosshair first, then any extra layers
+    overlay_handles: Vec<OverlayHandle>,
+    
+    // Offsets saved by the last center, so an accidental center can be undone
+    pre_center_offset: Option<(i32, i32)>,
+    
+    // Crosshair can be dragged with the mouse (click-through disabled)
+    positioning_mode: bool,
+    // Crosshair restarted during positioning; its window may not exist yet to make draggable
+    click_through_pending: bool,
     
     // Tray manager (kept in app state since TrayIcon is !Send)
     tray_manager: Option<TrayFlyoutManager>,
+    
+    /// Hide to tray on close instead of exiting (stored in config.json)
+    close_to_tray: bool,
+    /// Desktop notifications on activation/deactivation (stored in config.json)
+    show_notifications: bool,
+    /// Acrylic tray flyout on Windows 11 (stored in config.json, read when the flyout opens)
+    flyout_backdrop: bool,
+    /// Ask before switching away from the active profile (stored in config.json)
+    lock_active_profile: bool,
+    /// Start with the window hidden in the tray (stored in config.json, read at launch)
+    start_minimized: bool,
+    /// Global mute for activation sounds (stored in config.json)
+    mute_sounds: bool,
+    /// Profile activated at launch (stored in config.json)
+    default_profile_on_startup: Option<String>,
+    /// Apps that need an OK before activation closes them (stored in config.json, read at launch)
+    confirm_before_killing: Vec<String>,
+    
+    /// What the right panel shows
+    page: Page,
+    /// Tail of the log file while the Logs page is open
+    log_lines: Vec<String>,
+    /// A file is being dragged over the window
+    file_hovering: bool,
+    /// Names of profiles whose crosshair image files are gone (refreshed on load, save and select)
+    missing_images: HashSet<String>,
+    /// Common apps ticked on the welcome page for the starter profile
+    welcome_apps: HashSet<String>,
+    /// Starter profile uses the built-in crosshair image
+    welcome_crosshair: bool,
+    /// Open "Copy settings" panel below the editor buttons
+    copy_settings: Option<CopySettingsForm>,
+}
+
+/// What the "Copy settings" panel will copy from the selected profile, and into which profiles
+struct CopySettingsForm {
+    fields: CopyFields,
+    targets: HashSet<usize>,
+}
+
+/// How often the exit-watched process is looked up
+const EXIT_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Deactivate-on-exit state for the active profile
+struct ExitWatch {
+    process: String,
+    /// Only an exit after the process was seen counts, so activating before launching the game works
+    seen_running: bool,
+    last_check: Instant,
+}
+
+impl ExitWatch {
+    fn new(process: String) -> Self {
+        ExitWatch { process, seen_running: false, last_check: Instant::now() }
+    }
+}
+
+/// Programs listed by the performance scan
+const QUICK_SCAN_RESULTS: usize = 8;
+
+/// How long a newly activated profile's sidebar entry pulses
+const ACTIVATION_FLASH: Duration = Duration::from_millis(1500);
+
+/// Lines of the log file the Logs page shows
+const LOG_VIEW_LINES: usize = 500;
+
+/// Right-panel pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    /// First-run setup, shown once when there is no profiles.json yet
+    Welcome,
+    Profiles,
+    Logs,
+}
+
+impl Page {
+    /// Name stored in config.json's last_page (the welcome page is never restored)
+    fn config_name(self) -> &'static str {
+        match self {
+            Page::Welcome | Page::Profiles => "profiles",
+            Page::Logs => "logs",
+        }
+    }
+    
+    fn from_config_name(name: &str) -> Page {
+        match name {
+            "logs" => Page::Logs,
+            _ => Page::Profiles,
+        }
+    }
+}
+
+/// Profile editor text inputs, in Tab order (top to bottom as laid out)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorField {
+    Name,
+    Tags,
+    DeactivateOnExit,
+    ProcessFilter,
+    GameProcess,
+    XOffset,
+    YOffset,
+}
+
+impl EditorField {
+    const ORDER: [EditorField; 7] = [
+        EditorField::Name,
+        EditorField::Tags,
+        EditorField::DeactivateOnExit,
+        EditorField::ProcessFilter,
+        EditorField::GameProcess,
+        EditorField::XOffset,
+        EditorField::YOffset,
+    ];
+    
+    fn id(self) -> text_input::Id {
+        text_input::Id::new(match self {
+            EditorField::Name => "editor-name",
+            EditorField::Tags => "editor-tags",
+            EditorField::DeactivateOnExit => "editor-deactivate-on-exit",
+            EditorField::ProcessFilter => "editor-process-filter",
+            EditorField::GameProcess => "editor-game-process",
+            EditorField::XOffset => "editor-x-offset",
+            EditorField::YOffset => "editor-y-offset",
+        })
+    }
+    
+    fn from_id(id: &WidgetId) -> Option<EditorField> {
+        Self::ORDER.into_iter().find(|field| WidgetId::from(field.id()) == *id)
+    }
+    
+    /// Field that Tab (or Shift+Tab) moves to, wrapping around; from outside the editor's
+    /// inputs it starts at the first (or last) one
+    fn after(current: Option<EditorField>, backwards: bool) -> EditorField {
+        let len = Self::ORDER.len();
+        let index = match (current.and_then(|field| Self::ORDER.iter().position(|f| *f == field)), backwards) {
+            (Some(i), false) => (i + 1) % len,
+            (Some(i), true) => (i + len - 1) % len,
+            (None, false) => 0,
+            (None, true) => len - 1,
+        };
+        Self::ORDER[index]
+    }
+}
+
+/// Widget operation reporting the focused widget's id, or None when nothing with an id has
+/// focus (iced's find_focused reports nothing at all then, so Tab would never start the cycle)
+struct FindFocusedInput(Option<WidgetId>);
+
+impl Operation<Option<WidgetId>> for FindFocusedInput {
+    fn container(
+        &mut self,
+        _id: Option<&WidgetId>,
+        _bounds: iced::Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<Option<WidgetId>>),
+    ) {
+        operate_on_children(self);
+    }
+    
+    fn focusable(&mut self, state: &mut dyn Focusable, id: Option<&WidgetId>) {
+        if state.is_focused() {
+            self.0 = id.cloned();
+        }
+    }
+    
+    fn finish(&self) -> Outcome<Option<WidgetId>> {
+        Outcome::Some(self.0.clone())
+    }
 }
 
 /// Tray action to be processed by the app
@@ -118,6 +472,12 @@ enum TrayAction {
     ShowFlyout,
     HideFlyout,
     ProfileSelected(String),
+    ShowWindow,
+    ToggleCrosshair,
+    /// The active profile's crosshair cycle shortcut was pressed
+    CycleCrosshair,
+    /// Menu item without a fixed ID, e.g. an entry in the Profiles submenu
+    MenuItem(tray_icon::menu::MenuId),
     Exit,
     None,
 }
@@ -140,6 +500,11 @@ fn process_tray_events() -> TrayAction {
         }
     }
     
+    // Queued by the hotkey window, whichever message loop dispatched WM_HOTKEY
+    if hotkey::take_pressed().contains(&CYCLE_HOTKEY_ID) {
+        return TrayAction::CycleCrosshair;
+    }
+    
     // Check for profile activation from flyout
     if let Ok(guard) = FLYOUT_PROFILE_RX.lock() {
         if let Some(ref rx) = *guard {
@@ -163,6 +528,17 @@ fn process_tray_events() -> TrayAction {
                         }
                     }
                 }
+                if let Ok(crosshair_guard) = MENU_TOGGLE_CROSSHAIR_ID.lock() {
+                    if crosshair_guard.as_ref() == Some(&event.id) {
+                        return TrayAction::ToggleCrosshair;
+                    }
+                }
+                if let Ok(settings_guard) = MENU_SETTINGS_ID.lock() {
+                    if settings_guard.as_ref() == Some(&event.id) {
+                        return TrayAction::ShowWindow;
+                    }
+                }
+                return TrayAction::MenuItem(event.id);
             }
         }
     }
@@ -195,8 +571,8 @@ fn process_tray_events() -> TrayAction {
                                 if let Ok(mut guard) = PENDING_SINGLE_CLICK.lock() {
                                     *guard = false;
                                 }
-                                println!("[GUI] Double-click detected - GUI already open");
-                                // GUI is already open, nothing to do
+                                println!("[GUI] Double-click detected - showing window");
+                                return TrayAction::ShowWindow;
                             } else {
                                 // First click - start timer
                                 if let Ok(mut guard) = LAST_CLICK_TIME.lock() {
@@ -250,16 +626,29 @@ impl GameOptimizer {
             match load_profiles(data_dir) {
                 Ok(profiles) => {
                     self.profiles = profiles;
+                    self.refresh_missing_images();
+                    self.prune_tag_filter();
                     self.status_message = format!("Loaded {} profiles", self.profiles.len());
                 }
                 Err(e) => {
+                    println!("[GUI] Failed to load profiles: {}", e);
                     self.status_message = format!("Failed to load profiles: {}", e);
                 }
             }
         }
     }
     
+    fn refresh_missing_images(&mut self) {
+        self.missing_images = self.profiles
+            .iter()
+            .filter(|p| !p.missing_images().is_empty())
+            .map(|p| p.name.clone())
+            .collect();
+    }
+    
     fn save_profiles_to_disk(&mut self) {
+        self.refresh_missing_images();
+        self.prune_tag_filter();
         if let Some(ref data_dir) = self.data_dir {
             match save_profiles(&self.profiles, data_dir) {
                 Ok(_) => {
@@ -272,30 +661,137 @@ impl GameOptimizer {
         }
     }
     
+    fn refresh_audio_devices(&mut self) {
+        match audio::list_output_devices() {
+            Ok(devices) => self.audio_devices = devices.into_iter().map(|d| d.name).collect(),
+            Err(e) => println!("[GUI] Failed to list audio devices: {}", e),
+        }
+    }
+    
+    /// Activation sound dropdown: no sound, the built-in cues, and the profile's own file if any
+    fn activation_sound_options(&self) -> Vec<String> {
+        let mut options = vec![NO_SOUND.to_string()];
+        options.extend(sound::BUILTIN_CUES.iter().map(|(label, _)| label.to_string()));
+        if let Some(ref cue) = self.edit_activation_sound {
+            let label = sound::cue_label(cue);
+            if !options.contains(&label) {
+                options.push(label);
+            }
+        }
+        options
+    }
+    
+    /// Audio dropdown entries; keeps a saved device listed even while it's unplugged
+    fn audio_device_options(&self) -> Vec<String> {
+        let mut options = vec![KEEP_AUDIO_DEVICE.to_string()];
+        options.extend(self.audio_devices.iter().cloned());
+        if let Some(ref saved) = self.edit_audio_device {
+            if !options.contains(saved) {
+                options.push(saved.clone());
+            }
+        }
+        options
+    }
+    
+    /// Dropdown label for a profile's power plan
+    fn power_plan_label(&self, plan: &Option<PowerPlanGuid>) -> String {
+        match plan {
+            None => KEEP_POWER_PLAN.to_string(),
+            Some(guid) => self.power_plans
+                .iter()
+                .find(|p| &p.guid == guid)
+                .map(|p| p.name.clone())
+                .unwrap_or_else(|| guid.0.clone()),
+        }
+    }
+    
+    fn power_plan_options(&self) -> Vec<String> {
+        let mut options = vec![KEEP_POWER_PLAN.to_string()];
+        options.extend(self.power_plans.iter().map(|p| p.name.clone()));
+        let current = self.power_plan_label(&self.edit_power_plan);
+        if !options.contains(&current) {
+            options.push(current);
+        }
+        options
+    }
+    
     fn refresh_running_processes(&mut self) {
-        self.running_processes = list_processes();
+        self.running_processes = self.process_sampler.list();
         self.running_processes.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        
+        let running: HashSet<String> = self.running_processes.iter().map(|p| p.name.to_lowercase()).collect();
+        self.common_app_status = COMMON_APPS.iter().map(|(_, exe)| app_status(exe, &running)).collect();
     }
     
     fn clear_edit_form(&mut self) {
+        self.stop_positioning();
+        self.copy_settings = None;
         self.edit_name = String::new();
+        self.edit_description = text_editor::Content::new();
+        self.edit_tags.clear();
         self.edit_x_offset = "0".to_string();
         self.edit_y_offset = "0".to_string();
         self.edit_image_path = None;
         self.edit_overlay_enabled = false;
         self.edit_fan_speed_max = false;
+        self.edit_audio_device = None;
+        self.edit_power_plan = None;
+        self.edit_trim_memory = false;
+        self.edit_deactivate_on_exit = String::new();
+        self.edit_activation_sound = None;
+        self.edit_priority_rules.clear();
+        self.edit_crosshair_kind = CrosshairKind::default();
+        self.edit_crosshair_size = crosshair_shapes::DEFAULT_SIZE;
+        self.edit_crosshair_thickness = crosshair_shapes::DEFAULT_THICKNESS;
+        self.edit_crosshair_color = crosshair_shapes::DEFAULT_COLOR;
+        self.edit_crosshair_gap = crosshair_shapes::DEFAULT_GAP;
+        self.edit_crosshair_refresh = RefreshRate::AUTO;
+        self.edit_aggressive_topmost = true;
+        self.edit_topmost_interval = TopmostInterval::DEFAULT;
+        self.edit_topmost_on_foreground = false;
+        self.edit_hide_when_unfocused = false;
+        self.edit_game_process.clear();
+        self.edit_crosshair_layers.clear();
+        self.edit_crosshair_presets.clear();
+        self.edit_cycle_shortcut.clear();
         self.process_selection.clear();
         self.selected_profile_index = None;
+        self.pre_center_offset = None;
     }
     
     fn load_profile_to_edit(&mut self, index: usize) {
+        // A drag in progress belongs to the profile being left
+        self.stop_positioning();
         if let Some(profile) = self.profiles.get(index) {
+            self.copy_settings = None;
             self.edit_name = profile.name.clone();
+            self.edit_description = text_editor::Content::with_text(&profile.description);
+            self.edit_tags = profile.tags.join(", ");
             self.edit_x_offset = profile.crosshair_x_offset.to_string();
             self.edit_y_offset = profile.crosshair_y_offset.to_string();
             self.edit_image_path = profile.crosshair_image_path.clone();
             self.edit_overlay_enabled = profile.overlay_enabled;
             self.edit_fan_speed_max = profile.fan_speed_max;
+            self.edit_audio_device = profile.default_audio_device.clone();
+            self.edit_power_plan = profile.power_plan.clone();
+            self.edit_trim_memory = profile.trim_memory;
+            self.edit_deactivate_on_exit = profile.deactivate_on_process_exit.clone().unwrap_or_default();
+            self.edit_activation_sound = profile.activation_sound.clone();
+            self.edit_priority_rules = profile.process_priority_rules.clone();
+            self.edit_crosshair_kind = profile.crosshair_kind;
+            self.edit_crosshair_size = profile.crosshair_size;
+            self.edit_crosshair_thickness = profile.crosshair_thickness;
+            self.edit_crosshair_color = profile.crosshair_color;
+            self.edit_crosshair_gap = profile.crosshair_gap;
+            self.edit_crosshair_refresh = RefreshRate(profile.crosshair_refresh_hz);
+            self.edit_aggressive_topmost = profile.aggressive_topmost;
+            self.edit_topmost_interval = TopmostInterval(profile.topmost_interval_ms);
+            self.edit_topmost_on_foreground = profile.topmost_on_foreground;
+            self.edit_hide_when_unfocused = profile.hide_crosshair_when_unfocused;
+            self.edit_game_process = profile.game_process.clone().unwrap_or_default();
+            self.edit_crosshair_layers = profile.crosshair_layers.clone();
+            self.edit_crosshair_presets = profile.crosshair_presets.clone();
+            self.edit_cycle_shortcut = profile.cycle_crosshair_shortcut.clone().unwrap_or_default();
             
             self.process_selection.clear();
             for proc in &profile.processes_to_kill {
@@ -303,9 +799,15 @@ impl GameOptimizer {
             }
             
             self.selected_profile_index = Some(index);
+            self.pre_center_offset = None;
         }
     }
     
+    /// Check the name against every profile except `exclude_index` (case-insensitive)
+    fn profiles_name_unique(&self, name: &str, exclude_index: Option<usize>) -> bool {
+        is_profile_name_unique(&self.profiles, name.trim(), exclude_index)
+    }
+    
     fn get_selected_processes(&self) -> Vec<String> {
         self.process_selection
             .iter()
@@ -315,6 +817,9 @@ impl GameOptimizer {
     }
     
     fn activate_profile_by_name(&mut self, name: &str) {
+        if !self.switch_allowed(name) {
+            return;
+        }
         if let Some(index) = self.profiles.iter().position(|p| p.name == name) {
             self.selected_profile_index = Some(index);
             self.load_profile_to_edit(index);
@@ -322,18 +827,65 @@ impl GameOptimizer {
         }
     }
     
+    /// With the profile lock on, switching from the active profile to another needs a Yes
+    /// Covers the flyout and tray menu too, which both activate through activate_profile_by_name
+    fn switch_allowed(&mut self, target: &str) -> bool {
+        if !self.lock_active_profile {
+            return true;
+        }
+        let Some(active) = self.active_profile_name.clone() else {
+            return true;
+        };
+        if active == target {
+            return true;
+        }
+        
+        let allowed = confirm_dialog(
+            "Active profile is locked",
+            &format!("'{}' is active and locked. Switch to '{}' anyway?", active, target),
+        );
+        if !allowed {
+            self.status_message = format!("🔒 Kept '{}' active", active);
+        }
+        allowed
+    }
+    
     fn activate_current_profile(&mut self) {
         if let Some(index) = self.selected_profile_index {
             if let Some(profile) = self.profiles.get(index) {
                 let profile_name = profile.name.clone();
                 let processes = profile.processes_to_kill.clone();
                 let fan_max = profile.fan_speed_max;
+                let audio_device = profile.default_audio_device.clone();
+                let power_plan = profile.power_plan.clone();
+                let trim_memory = profile.trim_memory;
+                let game_process = profile.game_process.clone();
+                let priority_rules = profile.process_priority_rules.clone();
                 let overlay_enabled = profile.overlay_enabled;
-                let image_path = profile.crosshair_image_path.clone();
-                let x_offset = profile.crosshair_x_offset;
-                let y_offset = profile.crosshair_y_offset;
+                let has_image = profile.crosshair_image_path.is_some();
+                let activation_sound = profile.activation_sound.clone();
                 
-                let report = kill_processes(&processes);
+                // Apps that may have unsaved work need an explicit OK
+                let risky = running_confirm_targets(&processes, &self.confirm_before_killing);
+                if !risky.is_empty() {
+                    let proceed = confirm_dialog(
+                        "Apps may have unsaved work",
+                        &format!(
+                            "Activating '{}' will close:\n\n{}\n\nUnsaved work in these apps will be lost. Continue?",
+                            profile_name,
+                            risky.join("\n")
+                        ),
+                    );
+                    if !proceed {
+                        self.status_message = format!("Activation of '{}' cancelled", profile_name);
+                        return;
+                    }
+                }
+                
+                // Switching profiles: undo the previous profile's priority changes first
+                self.reset_active_priorities();
+                
+                let report = kill_processes(&processes, false);
                 
                 let mut status_parts = Vec::new();
                 
@@ -343,38 +895,92 @@ impl GameOptimizer {
                 if !report.not_found.is_empty() {
                     status_parts.push(format!("Not running: {}", report.not_found.join(", ")));
                 }
-                if !report.blocklist_skipped.is_empty() {
-                    status_parts.push(format!("Protected: {}", report.blocklist_skipped.join(", ")));
+                let mut reasons: Vec<ProtectionReason> = Vec::new();
+                for (_, reason) in &report.blocklist_skipped {
+                    if !reasons.contains(reason) {
+                        reasons.push(*reason);
+                    }
+                }
+                for reason in reasons {
+                    let skipped: Vec<&str> = report.blocklist_skipped
+                        .iter()
+                        .filter(|(_, r)| *r == reason)
+                        .map(|(name, _)| name.as_str())
+                        .collect();
+                    if !skipped.is_empty() {
+                        status_parts.push(format!("Protected ({}): {}", reason, skipped.join(", ")));
+                    }
                 }
                 
+                self.last_kill_report = Some((profile_name.clone(), report.clone()));
                 self.active_profile_name = Some(profile_name.clone());
+                self.exit_watch = self.profiles[index].deactivate_on_process_exit.clone().map(ExitWatch::new);
+                self.record_activation(index);
                 
                 if fan_max {
                     status_parts.push("Fan: MAX".to_string());
                 }
                 
+                if let Some(ref plan) = power_plan {
+                    // Remember the user's own plan only once, so switching profiles still restores it
+                    if self.previous_power_plan.is_none() {
+                        self.previous_power_plan = power::active_power_plan().ok();
+                    }
+                    match power::set_active_power_plan(plan) {
+                        Ok(()) => status_parts.push(format!("⚡ {}", self.power_plan_label(&Some(plan.clone())))),
+                        Err(e) => println!("[GUI] Skipping power plan switch: {}", e),
+                    }
+                } else {
+                    self.restore_power_plan();
+                }
+                
+                // An unplugged device just skips the switch
+                if let Some(ref device) = audio_device {
+                    match audio::set_default_output(device) {
+                        Ok(()) => status_parts.push(format!("🔊 {}", device)),
+                        Err(e) => {
+                            println!("[GUI] Skipping audio switch: {}", e);
+                            status_parts.push(format!("⚠️ {}", e));
+                        }
+                    }
+                }
+                
+                if let Some(adjusted) = apply_priority_rules(&priority_rules, &mut self.priority_changes) {
+                    status_parts.push(adjusted);
+                }
+                
+                if trim_memory {
+                    // Never page out the game itself
+                    let keep: Vec<String> = game_process.into_iter().collect();
+                    let trim = optimize::trim_working_sets(&keep);
+                    println!("[GUI] Trimmed working sets: {:?}", trim);
+                    status_parts.push(format!("🧹 {}", trim.summary()));
+                }
+                
                 // Handle crosshair overlay
                 // First, stop any existing overlay
-                if let Some(ref mut handle) = self.overlay_handle {
-                    handle.stop();
-                }
-                self.overlay_handle = None;
+                self.stop_overlays();
                 
-                // Start new overlay if enabled and image path exists
+                // Start new overlay if enabled (image if set, otherwise the procedural shape)
                 if overlay_enabled {
-                    if let Some(ref path) = image_path {
-                        match crosshair_overlay::start_overlay(path.clone(), x_offset, y_offset) {
-                            Ok(handle) => {
-                                self.overlay_handle = Some(handle);
-                                status_parts.push("🎯 Crosshair ON".to_string());
-                            }
-                            Err(e) => {
-                                status_parts.push(format!("Crosshair error: {}", e));
-                            }
-                        }
-                    } else {
-                        status_parts.push("Crosshair: No image".to_string());
+                    match self.start_profile_overlay(index) {
+                        Ok(()) => status_parts.push("🎯 Crosshair ON".to_string()),
+                        Err(e) => status_parts.push(format!("Crosshair error: {}", e)),
                     }
+                } else if has_image {
+                    status_parts.push("Crosshair configured but disabled".to_string());
+                }
+                
+                // Asynchronous, so the sound never holds up activation
+                if let Some(ref cue) = activation_sound.filter(|_| !self.mute_sounds) {
+                    sound::play(cue);
+                }
+                
+                if self.show_notifications {
+                    notifications::notify(
+                        &format!("Activated {}", profile_name),
+                        &notifications::activation_summary(report.killed.len(), !self.overlay_handles.is_empty()),
+                    );
                 }
                 
                 if status_parts.is_empty() {
@@ -384,6 +990,11 @@ impl GameOptimizer {
                 }
                 
                 self.refresh_running_processes();
+                self.persist_active_state();
+                self.sync_cycle_hotkey();
+                
+                // Hotkey, tray and flyout activations happen out of view - make it visible
+                self.activation_flash = Some((profile_name.clone(), Instant::now()));
                 
                 // Update tray with new active profile
                 self.update_tray();
@@ -393,128 +1004,852 @@ impl GameOptimizer {
         }
     }
     
-    fn deactivate_profile(&mut self) {
-        self.active_profile_name = None;
+    /// The editor's current values as a profile (None while an offset doesn't parse)
+    /// Usage stats are carried over from the selected profile
+    fn form_profile(&self) -> Option<Profile> {
+        let (Ok(x_offset), Ok(y_offset)) = (self.edit_x_offset.parse(), self.edit_y_offset.parse()) else {
+            return None;
+        };
         
-        // Stop overlay when deactivating
-        if let Some(ref mut handle) = self.overlay_handle {
-            handle.stop();
+        Some(Profile {
+            name: self.edit_name.clone(),
+            description: self.edit_description.text().trim_end().to_string(),
+            tags: parse_tags(&self.edit_tags),
+            processes_to_kill: self.get_selected_processes(),
+            crosshair_image_path: self.edit_image_path.clone(),
+            crosshair_x_offset: x_offset,
+            crosshair_y_offset: y_offset,
+            overlay_enabled: self.edit_overlay_enabled,
+            fan_speed_max: self.edit_fan_speed_max,
+            default_audio_device: self.edit_audio_device.clone(),
+            power_plan: self.edit_power_plan.clone(),
+            trim_memory: self.edit_trim_memory,
+            deactivate_on_process_exit: Some(self.edit_deactivate_on_exit.trim().to_string()).filter(|p| !p.is_empty()),
+            activation_sound: self.edit_activation_sound.clone(),
+            process_priority_rules: self.edit_priority_rules.clone(),
+            last_activated: self.selected_profile_index.and_then(|i| self.profiles[i].last_activated),
+            activation_count: self.selected_profile_index.map(|i| self.profiles[i].activation_count).unwrap_or(0),
+            crosshair_kind: self.edit_crosshair_kind,
+            crosshair_size: self.edit_crosshair_size,
+            crosshair_thickness: self.edit_crosshair_thickness,
+            crosshair_color: self.edit_crosshair_color,
+            crosshair_gap: self.edit_crosshair_gap,
+            crosshair_refresh_hz: self.edit_crosshair_refresh.0,
+            aggressive_topmost: self.edit_aggressive_topmost,
+            topmost_interval_ms: self.edit_topmost_interval.0,
+            topmost_on_foreground: self.edit_topmost_on_foreground,
+            hide_crosshair_when_unfocused: self.edit_hide_when_unfocused,
+            game_process: Some(self.edit_game_process.trim().to_string()).filter(|p| !p.is_empty()),
+            crosshair_layers: self.edit_crosshair_layers.clone(),
+            crosshair_presets: self.edit_crosshair_presets.clone(),
+            cycle_crosshair_shortcut: Some(self.edit_cycle_shortcut.trim().to_string()).filter(|s| !s.is_empty()),
+        })
+    }
+    
+    /// The editor differs from the saved copy of the selected profile
+    fn has_unsaved_changes(&self) -> bool {
+        let Some(saved) = self.selected_profile_index.and_then(|i| self.profiles.get(i)) else {
+            return false;
+        };
+        !self.form_profile().is_some_and(|form| same_settings(&form, saved))
+    }
+    
+    /// Save the edit form into the profile list; false if validation failed or the user cancelled
+    fn save_edit_form(&mut self) -> bool {
+        if self.edit_name.trim().is_empty() {
+            self.status_message = "❌ Error: Profile name cannot be empty".to_string();
+            return false;
         }
-        self.overlay_handle = None;
         
-        self.status_message = "Profile deactivated".to_string();
+        let Some(profile) = self.form_profile() else {
+            self.status_message = "❌ Error: Crosshair offsets must be whole numbers".to_string();
+            return false;
+        };
+        
+        if !self.profiles_name_unique(&self.edit_name, self.selected_profile_index) {
+            let overwrite = confirm_dialog(
+                "Profile already exists",
+                &format!("A profile named '{}' already exists. Overwrite it?", self.edit_name),
+            );
+            if !overwrite {
+                self.status_message = format!("❌ Not saved: a profile named '{}' already exists", self.edit_name);
+                return false;
+            }
+            
+            let name_lower = self.edit_name.to_lowercase();
+            if let Some(index) = self.selected_profile_index {
+                self.profiles.remove(index);
+            }
+            if let Some(existing) = self.profiles.iter().position(|p| p.name.to_lowercase() == name_lower) {
+                self.profiles[existing] = profile;
+                self.selected_profile_index = Some(existing);
+            }
+            self.status_message = format!("✅ Overwrote profile: {}", self.edit_name);
+        } else if let Some(index) = self.selected_profile_index {
+            let old_name = std::mem::replace(&mut self.profiles[index], profile).name;
+            // A renamed startup profile stays the startup profile
+            if old_name != self.edit_name && self.default_profile_on_startup.as_ref() == Some(&old_name) {
+                self.set_startup_profile(Some(self.edit_name.clone()));
+            }
+            self.status_message = format!("✅ Updated profile: {}", self.edit_name);
+        } else {
+            self.profiles.push(profile);
+            self.selected_profile_index = Some(self.profiles.len() - 1);
+            self.status_message = format!("✅ Created profile: {}", self.edit_name);
+        }
+        
+        self.save_profiles_to_disk();
         self.update_tray();
+        self.sync_cycle_hotkey();
+        true
     }
     
-    /// Update the live crosshair overlay with new offsets (restarts if running)
-    fn update_live_overlay(&mut self) {
-        // Only update if we have an active overlay
-        if self.overlay_handle.is_some() {
-            // Stop existing overlay
-            if let Some(ref handle) = self.overlay_handle {
-                handle.stop();
+    /// Re-run the overlay, fan, power plan and priority steps for the active profile in place
+    /// Processes are not killed again, so this is cheap enough for an edit-and-test loop
+    fn reapply_active_profile(&mut self, index: usize) {
+        let profile = &self.profiles[index];
+        let name = profile.name.clone();
+        let fan_max = profile.fan_speed_max;
+        let power_plan = profile.power_plan.clone();
+        let priority_rules = profile.process_priority_rules.clone();
+        let overlay_enabled = profile.overlay_enabled;
+        
+        self.active_profile_name = Some(name.clone());
+        self.exit_watch = self.profiles[index].deactivate_on_process_exit.clone().map(ExitWatch::new);
+        let mut status_parts = Vec::new();
+        
+        if fan_max {
+            status_parts.push("Fan: MAX".to_string());
+        }
+        
+        if let Some(ref plan) = power_plan {
+            if self.previous_power_plan.is_none() {
+                self.previous_power_plan = power::active_power_plan().ok();
             }
-            self.overlay_handle = None;
-            
-            // Restart with new offsets if we have an image
-            if self.edit_overlay_enabled {
-                if let Some(ref path) = self.edit_image_path {
-                    let x_offset: i32 = self.edit_x_offset.parse().unwrap_or(0);
-                    let y_offset: i32 = self.edit_y_offset.parse().unwrap_or(0);
-                    
-                    match crosshair_overlay::start_overlay(path.clone(), x_offset, y_offset) {
-                        Ok(handle) => {
-                            self.overlay_handle = Some(handle);
-                        }
-                        Err(e) => {
-                            self.status_message = format!("Crosshair error: {}", e);
-                        }
-                    }
-                }
+            match power::set_active_power_plan(plan) {
+                Ok(()) => status_parts.push(format!("⚡ {}", self.power_plan_label(&Some(plan.clone())))),
+                Err(e) => println!("[GUI] Skipping power plan switch: {}", e),
+            }
+        } else {
+            self.restore_power_plan();
+        }
+        
+        if let Some(adjusted) = apply_priority_rules(&priority_rules, &mut self.priority_changes) {
+            status_parts.push(adjusted);
+        }
+        
+        self.stop_overlays();
+        self.positioning_mode = false;
+        if overlay_enabled {
+            match self.start_profile_overlay(index) {
+                Ok(()) => status_parts.push("🎯 Crosshair ON".to_string()),
+                Err(e) => status_parts.push(format!("Crosshair error: {}", e)),
             }
         }
+        
+        self.status_message = if status_parts.is_empty() {
+            format!("🔁 Saved and reapplied '{}'", name)
+        } else {
+            format!("🔁 Saved and reapplied '{}': {}", name, status_parts.join(" | "))
+        };
+        self.persist_active_state();
+        self.update_tray();
     }
     
-    fn update_tray(&mut self) {
-        // Update tray with current profiles
-        if let Some(ref mut tray) = self.tray_manager {
-            tray.update_profiles(self.profiles.clone());
-            tray.set_active_profile(self.active_profile_name.clone());
+    /// Deactivate once the watched process has been seen and then disappears
+    fn poll_exit_watch(&mut self) {
+        let Some(watch) = self.exit_watch.as_mut() else {
+            return;
+        };
+        if watch.last_check.elapsed() < EXIT_WATCH_INTERVAL {
+            return;
         }
+        watch.last_check = Instant::now();
+        
+        if self.process_sampler.is_running(&watch.process) {
+            watch.seen_running = true;
+            return;
+        }
+        if !watch.seen_running {
+            return;
+        }
+        
+        let process = watch.process.clone();
+        println!("[GUI] {} exited, deactivating profile", process);
+        self.deactivate_profile();
+        self.status_message = format!("Profile deactivated: {} exited", process);
     }
     
-    fn toggle_flyout(&mut self) {
-        if let Some(ref mut tray) = self.tray_manager {
-            if tray.is_flyout_visible() {
-                tray.hide_flyout();
-            } else {
-                if let Err(e) = tray.show_flyout() {
-                    eprintln!("[GUI] Failed to show flyout: {}", e);
-                }
+    /// Re-read the log tail, returns true if it changed
+    fn reload_log_lines(&mut self) -> bool {
+        let lines = match logging::tail(LOG_VIEW_LINES) {
+            Ok(lines) => lines,
+            Err(e) => vec![format!("No log available: {}", e)],
+        };
+        let changed = lines != self.log_lines;
+        self.log_lines = lines;
+        changed
+    }
+    
+    /// Whether the profile open in the editor is the one currently active
+    fn selected_is_active(&self) -> bool {
+        self.selected_profile_index
+            .and_then(|i| self.profiles.get(i))
+            .is_some_and(|p| self.active_profile_name.as_deref() == Some(p.name.as_str()))
+    }
+    
+    /// Launch the crosshair for a saved profile (image if set, otherwise the procedural shape)
+    fn start_profile_overlay(&mut self, index: usize) -> Result<(), String> {
+        let profile = &self.profiles[index];
+        let source = match profile.crosshair_image_path {
+            Some(ref path) => CrosshairSource::Image(path.clone()),
+            None => CrosshairSource::Shape(profile.crosshair_shape()),
+        };
+        let options = OverlayOptions {
+            refresh: RefreshRate(profile.crosshair_refresh_hz),
+            aggressive_topmost: profile.aggressive_topmost,
+            topmost_interval: TopmostInterval(profile.topmost_interval_ms),
+            topmost_on_foreground: profile.topmost_on_foreground,
+            focus_process: profile.crosshair_focus_process(),
+            ..OverlayOptions::default()
+        };
+        let layers = profile.crosshair_layers.clone();
+        let handle = crosshair_overlay::start_overlay(source, profile.crosshair_x_offset, profile.crosshair_y_offset, options.clone())?;
+        self.overlay_handles = vec![handle];
+        self.start_crosshair_layers(&layers, options);
+        Ok(())
+    }
+    
+    /// Start the extra crosshair layers next to an already running main crosshair
+    /// A layer that fails to start is logged and skipped so the others still show
+    fn start_crosshair_layers(&mut self, layers: &[CrosshairLayer], options: OverlayOptions) {
+        for layer in layers {
+            let layer_options = OverlayOptions {
+                scale_percent: layer.scale_percent,
+                opacity_percent: layer.opacity_percent,
+                ..options.clone()
+            };
+            let source = CrosshairSource::Image(layer.image_path.clone());
+            match crosshair_overlay::add_overlay(source, layer.x_offset, layer.y_offset, layer_options) {
+                Ok(handle) => self.overlay_handles.push(handle),
+                Err(e) => println!("[GUI] Failed to start crosshair layer {}: {}", layer.image_path, e),
             }
         }
     }
-}
-
-impl Application for GameOptimizer {
-    type Executor = executor::Default;
-    type Message = Message;
-    type Theme = Theme;
-    type Flags = ();
-
-    fn new(_flags: ()) -> (Self, Command<Message>) {
-        let data_dir = get_data_directory().ok();
-        let mut app = GameOptimizer {
-            profiles: Vec::new(),
-            selected_profile_index: None,
-            edit_name: String::new(),
-            edit_x_offset: "0".to_string(),
-            edit_y_offset: "0".to_string(),
-            edit_image_path: None,
-            edit_overlay_enabled: false,
-            edit_fan_speed_max: false,
-            process_selection: HashMap::new(),
-            running_processes: Vec::new(),
-            process_filter: String::new(),
-            status_message: "Welcome to Gaming Optimizer".to_string(),
-            data_dir,
-            active_profile_name: None,
-            overlay_handle: None,
-            tray_manager: None,  // Will be set by run() via Flags if we change approach
+    
+    /// Stop the main crosshair and every layer
+    fn stop_overlays(&mut self) {
+        for handle in self.overlay_handles.drain(..) {
+            handle.stop();
+        }
+    }
+    
+    /// Flip overlay_enabled on the active profile and apply it right away (tray menu)
+    fn toggle_active_crosshair(&mut self) {
+        let Some(index) = self.active_profile_name
+            .as_ref()
+            .and_then(|name| self.profiles.iter().position(|p| &p.name == name))
+        else {
+            self.status_message = "⚠️ No active profile - activate one to toggle its crosshair".to_string();
+            return;
         };
-        app.load_profiles_from_disk();
-        app.refresh_running_processes();
         
-        // Create tray manager on main thread (inside iced's new)
-        let app_config = crate::config::load_config();
-        match TrayFlyoutManager::new_with_channels(app.profiles.clone(), app_config.active_profile) {
-            Ok((tray, event_rx, menu_rx, profile_rx)) => {
-                // Store the exit menu ID
-                if let Ok(mut guard) = MENU_EXIT_ID.lock() {
-                    *guard = Some(tray.menu_item_exit.clone());
-                }
-                // Store channels in globals
-                if let Ok(mut guard) = TRAY_EVENT_RX.lock() {
-                    *guard = Some(event_rx);
-                }
-                if let Ok(mut guard) = MENU_EVENT_RX.lock() {
-                    *guard = Some(menu_rx);
-                }
-                if let Ok(mut guard) = FLYOUT_PROFILE_RX.lock() {
-                    *guard = Some(profile_rx);
-                }
-                app.tray_manager = Some(tray);
-                println!("[GUI] Tray manager created successfully");
-            }
-            Err(e) => {
-                eprintln!("[GUI] Failed to create tray: {}", e);
+        let enabled = !self.profiles[index].overlay_enabled;
+        self.profiles[index].overlay_enabled = enabled;
+        if self.selected_profile_index == Some(index) {
+            self.edit_overlay_enabled = enabled;
+        }
+        if let Some(ref data_dir) = self.data_dir {
+            if let Err(e) = save_profiles(&self.profiles, data_dir) {
+                println!("[GUI] Failed to save crosshair toggle: {}", e);
             }
         }
         
-        (app, Command::none())
-    }
-
-    fn title(&self) -> String {
+        self.stop_overlays();
+        self.positioning_mode = false;
+        
+        self.status_message = if enabled {
+            match self.start_profile_overlay(index) {
+                Ok(()) => "🎯 Crosshair ON".to_string(),
+                Err(e) => format!("Crosshair error: {}", e),
+            }
+        } else {
+            "Crosshair OFF".to_string()
+        };
+        self.persist_active_state();
+    }
+    
+    /// Switch the active profile to its next crosshair preset and restart its crosshair
+    fn cycle_active_crosshair(&mut self) {
+        let Some(index) = self.active_profile_name
+            .as_ref()
+            .and_then(|name| self.profiles.iter().position(|p| &p.name == name))
+        else {
+            return;
+        };
+        
+        let Some(preset) = self.profiles[index].cycle_crosshair_preset().cloned() else {
+            self.status_message = "⚠️ The active profile has no crosshair presets".to_string();
+            return;
+        };
+        if self.selected_profile_index == Some(index) {
+            self.apply_preset_to_edit_form(&preset);
+        }
+        if let Some(ref data_dir) = self.data_dir {
+            if let Err(e) = save_profiles(&self.profiles, data_dir) {
+                println!("[GUI] Failed to save crosshair preset: {}", e);
+            }
+        }
+        
+        if self.profiles[index].overlay_enabled {
+            self.stop_overlays();
+            self.positioning_mode = false;
+            if let Err(e) = self.start_profile_overlay(index) {
+                self.status_message = format!("Crosshair error: {}", e);
+                return;
+            }
+        }
+        self.status_message = format!("🎯 Crosshair: {}", preset.name);
+    }
+    
+    /// Show a preset's crosshair in the edit form
+    fn apply_preset_to_edit_form(&mut self, preset: &CrosshairPreset) {
+        self.edit_image_path = preset.image_path.clone();
+        self.edit_crosshair_kind = preset.kind;
+        self.edit_crosshair_size = preset.size;
+        self.edit_crosshair_thickness = preset.thickness;
+        self.edit_crosshair_color = preset.color;
+        self.edit_crosshair_gap = preset.gap;
+        self.edit_x_offset = preset.x_offset.to_string();
+        self.edit_y_offset = preset.y_offset.to_string();
+    }
+    
+    /// Register the active profile's crosshair cycle shortcut, replacing the previous one
+    fn sync_cycle_hotkey(&mut self) {
+        let wanted = self.active_profile_name
+            .as_ref()
+            .and_then(|name| self.profiles.iter().find(|p| &p.name == name))
+            .filter(|p| !p.crosshair_presets.is_empty())
+            .and_then(|p| p.cycle_crosshair_shortcut.as_deref())
+            .and_then(|shortcut| Hotkey::parse(shortcut).ok());
+        if wanted == self.cycle_hotkey {
+            return;
+        }
+        
+        if self.cycle_hotkey.take().is_some() {
+            hotkey::unregister(CYCLE_HOTKEY_ID);
+        }
+        if let Some(shortcut) = wanted {
+            match hotkey::register(CYCLE_HOTKEY_ID, shortcut) {
+                Ok(()) => {
+                    println!("[GUI] Crosshair cycle shortcut registered: {}", shortcut);
+                    self.cycle_hotkey = Some(shortcut);
+                }
+                Err(e) => {
+                    println!("[GUI] Failed to register crosshair cycle shortcut: {}", e);
+                    self.status_message = format!("{} | ⚠️ Crosshair shortcut: {}", self.status_message, e);
+                }
+            }
+        }
+    }
+    
+    /// Stamp the activation time and persist it without touching the status bar
+    fn record_activation(&mut self, index: usize) {
+        if let Some(profile) = self.profiles.get_mut(index) {
+            profile.last_activated = Some(unix_now());
+            profile.activation_count += 1;
+        }
+        if let Some(ref data_dir) = self.data_dir {
+            if let Err(e) = save_profiles(&self.profiles, data_dir) {
+                println!("[GUI] Failed to save activation stats: {}", e);
+            }
+        }
+    }
+    
+    /// Every tag used by a profile, sorted (first spelling wins for case variants)
+    fn all_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.profiles.iter().flat_map(|p| &p.tags) {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.clone());
+            }
+        }
+        tags.sort_by_key(|t| t.to_lowercase());
+        tags
+    }
+    
+    /// Drop the sidebar tag filter once no profile has that tag anymore
+    fn prune_tag_filter(&mut self) {
+        if let Some(ref tag) = self.tag_filter {
+            if !self.profiles.iter().any(|p| p.has_tag(tag)) {
+                self.tag_filter = None;
+            }
+        }
+    }
+    
+    /// Indices into `profiles` in the order the sidebar shows them (only the filtered tag's)
+    fn sorted_profile_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.profiles.len())
+            .filter(|&i| self.tag_filter.as_ref().is_none_or(|tag| self.profiles[i].has_tag(tag)))
+            .collect();
+        match self.profile_sort {
+            ProfileSort::Saved => {}
+            ProfileSort::RecentlyUsed => {
+                // Never-activated profiles (None) sort last
+                indices.sort_by_key(|&i| std::cmp::Reverse(self.profiles[i].last_activated));
+            }
+            ProfileSort::MostUsed => {
+                indices.sort_by_key(|&i| std::cmp::Reverse(self.profiles[i].activation_count));
+            }
+        }
+        indices
+    }
+    
+    /// Put processes re-prioritized by the active profile back to the class they had before
+    fn reset_active_priorities(&mut self) {
+        let changes = std::mem::take(&mut self.priority_changes);
+        if !changes.is_empty() {
+            let restored = restore_priorities(&changes);
+            println!("[GUI] Restored priority of {}/{} processes", restored, changes.len());
+        }
+    }
+    
+    /// Switch back to the power plan that was active before a profile changed it
+    fn restore_power_plan(&mut self) {
+        if let Some(plan) = self.previous_power_plan.take() {
+            if let Err(e) = power::set_active_power_plan(&plan) {
+                println!("[GUI] Failed to restore power plan: {}", e);
+            }
+        }
+    }
+    
+    fn deactivate_profile(&mut self) {
+        self.reset_active_priorities();
+        self.restore_power_plan();
+        if self.show_notifications {
+            if let Some(ref name) = self.active_profile_name {
+                notifications::notify(&format!("Deactivated {}", name), "Crosshair off, priorities reset");
+            }
+        }
+        self.active_profile_name = None;
+        self.exit_watch = None;
+        self.positioning_mode = false;
+        
+        // Stop overlay when deactivating
+        self.stop_overlays();
+        
+        self.status_message = "Profile deactivated".to_string();
+        self.persist_active_state();
+        self.sync_cycle_hotkey();
+        self.update_tray();
+    }
+    
+    /// Update the live crosshair overlay with new offsets (restarts if running)
+    fn update_live_overlay(&mut self) {
+        // Only update if we have an active overlay
+        if !self.overlay_handles.is_empty() {
+            // Stop existing overlay
+            self.stop_overlays();
+            
+            // Restart with new offsets and crosshair settings
+            if self.edit_overlay_enabled {
+                let x_offset: i32 = self.edit_x_offset.parse().unwrap_or(0);
+                let y_offset: i32 = self.edit_y_offset.parse().unwrap_or(0);
+                
+                match crosshair_overlay::start_overlay(self.edit_crosshair_source(), x_offset, y_offset, self.edit_overlay_options()) {
+                    Ok(handle) => {
+                        // New windows start click-through, which would end the drag; the window
+                        // appears a moment after the process starts, so poll_positioning_offset retries
+                        self.click_through_pending = self.positioning_mode && handle.set_click_through(false).is_err();
+                        self.overlay_handles = vec![handle];
+                        let layers = self.edit_crosshair_layers.clone();
+                        self.start_crosshair_layers(&layers, self.edit_overlay_options());
+                    }
+                    Err(e) => {
+                        self.status_message = format!("Crosshair error: {}", e);
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Procedural crosshair settings from the edit form
+    fn edit_crosshair_shape(&self) -> CrosshairShape {
+        CrosshairShape {
+            kind: self.edit_crosshair_kind,
+            size: self.edit_crosshair_size,
+            thickness: self.edit_crosshair_thickness,
+            color: self.edit_crosshair_color,
+            gap: self.edit_crosshair_gap,
+        }
+    }
+    
+    /// Crosshair process options from the edit form
+    fn edit_overlay_options(&self) -> OverlayOptions {
+        OverlayOptions {
+            refresh: self.edit_crosshair_refresh,
+            aggressive_topmost: self.edit_aggressive_topmost,
+            topmost_interval: self.edit_topmost_interval,
+            topmost_on_foreground: self.edit_topmost_on_foreground,
+            focus_process: Some(self.edit_game_process.trim().to_string())
+                .filter(|p| self.edit_hide_when_unfocused && !p.is_empty()),
+            ..OverlayOptions::default()
+        }
+    }
+    
+    /// The edit form's image is a file that no longer exists
+    fn edit_image_missing(&self) -> bool {
+        self.edit_image_path
+            .as_deref()
+            .is_some_and(|p| !assets::is_builtin(p) && !std::path::Path::new(p).exists())
+    }
+    
+    /// Crosshair to draw for the edit form: the image if set, otherwise the shape
+    fn edit_crosshair_source(&self) -> CrosshairSource {
+        match self.edit_image_path {
+            Some(ref path) => CrosshairSource::Image(path.clone()),
+            None => CrosshairSource::Shape(self.edit_crosshair_shape()),
+        }
+    }
+    
+    /// Move the crosshair offsets by the given number of pixels
+    fn nudge_crosshair(&mut self, dx: i32, dy: i32) {
+        let x: i32 = self.edit_x_offset.parse().unwrap_or(0);
+        let y: i32 = self.edit_y_offset.parse().unwrap_or(0);
+        self.edit_x_offset = (x + dx).to_string();
+        self.edit_y_offset = (y + dy).to_string();
+        self.update_live_overlay();
+    }
+    
+    /// Enter or leave positioning mode on the live crosshair
+    fn set_positioning_mode(&mut self, enabled: bool) {
+        // The dragged offset is written into the editor, so it must hold the active profile
+        if enabled && !self.selected_is_active() {
+            self.positioning_mode = false;
+            self.status_message = "⚠️ Open the active profile in the editor to drag its crosshair".to_string();
+            return;
+        }
+        
+        // Positioning drags the main crosshair; layers keep their own offsets
+        let Some(handle) = self.overlay_handles.first() else {
+            self.positioning_mode = false;
+            self.status_message = "⚠️ Activate a profile with a crosshair to drag it".to_string();
+            return;
+        };
+        
+        match handle.set_click_through(!enabled) {
+            Ok(_) => {
+                self.positioning_mode = enabled;
+                self.click_through_pending = false;
+                self.status_message = if enabled {
+                    "🖐 Positioning mode: drag the crosshair, then turn this off".to_string()
+                } else {
+                    "Crosshair position captured - save the profile to keep it".to_string()
+                };
+            }
+            Err(e) => {
+                self.positioning_mode = false;
+                self.status_message = format!("Crosshair error: {}", e);
+            }
+        }
+    }
+    
+    /// Leave positioning mode (if on) and make the live crosshair click-through again
+    fn stop_positioning(&mut self) {
+        if std::mem::take(&mut self.positioning_mode) {
+            if let Some(handle) = self.overlay_handles.first() {
+                let _ = handle.set_click_through(true);
+            }
+        }
+    }
+    
+    /// Copy the dragged crosshair position into the offset fields
+    fn poll_positioning_offset(&mut self) {
+        if !self.positioning_mode {
+            return;
+        }
+        
+        let Some(handle) = self.overlay_handles.first() else {
+            return;
+        };
+        
+        if self.click_through_pending {
+            self.click_through_pending = handle.set_click_through(false).is_err();
+        }
+        
+        if let Some((x, y)) = handle.current_offset() {
+            self.edit_x_offset = x.to_string();
+            self.edit_y_offset = y.to_string();
+        }
+    }
+    
+    /// Remember the open page and profile in config.json so the next launch reopens them
+    fn remember_view(&self) {
+        let last_selected = self.selected_profile_index
+            .and_then(|i| self.profiles.get(i))
+            .map(|p| p.name.clone());
+        self.update_config(|c| {
+            c.last_page = self.page.config_name().to_string();
+            c.last_selected_profile = last_selected;
+        });
+    }
+    
+    /// Choose the profile activated at launch and write it to config.json
+    fn set_startup_profile(&mut self, name: Option<String>) {
+        self.default_profile_on_startup = name.clone();
+        self.update_config(|c| c.default_profile_on_startup = name);
+    }
+    
+    /// Load config.json, change it with `f` and write it back
+    fn update_config(&self, f: impl FnOnce(&mut AppConfig)) {
+        let mut app_config = crate::config::load_config();
+        f(&mut app_config);
+        if let Err(e) = crate::config::save_config(&app_config) {
+            println!("[GUI] Failed to save config: {}", e);
+        }
+    }
+    
+    /// Replace the app settings with ones from a backup, keeping this session's state
+    /// (active profile, crosshair, last view); false if they couldn't be saved
+    fn restore_settings(&mut self, mut restored: AppConfig) -> bool {
+        let current = crate::config::load_config();
+        restored.active_profile = current.active_profile;
+        restored.overlay_visible = current.overlay_visible;
+        restored.last_page = current.last_page;
+        restored.last_selected_profile = current.last_selected_profile;
+        // The backup's startup profile may not be among the restored ones
+        restored.validate_startup_profile(self.profiles.iter().map(|p| p.name.as_str()));
+        
+        if let Err(e) = crate::config::save_config(&restored) {
+            println!("[GUI] Failed to restore settings: {}", e);
+            return false;
+        }
+        self.apply_settings(&restored);
+        true
+    }
+    
+    /// Copy the settings the GUI keeps in memory out of config.json
+    fn apply_settings(&mut self, config: &AppConfig) {
+        self.close_to_tray = config.close_to_tray;
+        self.show_notifications = config.show_notifications;
+        self.flyout_backdrop = config.flyout_backdrop;
+        self.lock_active_profile = config.lock_active_profile;
+        self.start_minimized = config.start_minimized;
+        self.mute_sounds = config.mute_sounds;
+        self.default_profile_on_startup = config.default_profile_on_startup.clone();
+        self.confirm_before_killing = config.confirm_before_killing.clone();
+    }
+    
+    /// Startup profile dropdown: nothing active, then every profile
+    fn startup_profile_options(&self) -> Vec<String> {
+        let mut options = vec![NO_STARTUP_PROFILE.to_string()];
+        options.extend(self.profiles.iter().map(|p| p.name.clone()));
+        options
+    }
+    
+    /// Publish the active profile to `--status` queries and record it in config.json
+    fn persist_active_state(&self) {
+        crate::ipc::set_live_status(crate::ipc::ActiveProfileIs {
+            active_profile: self.active_profile_name.clone(),
+            overlay_visible: !self.overlay_handles.is_empty(),
+        });
+        
+        self.update_config(|c| {
+            c.active_profile = self.active_profile_name.clone();
+            c.overlay_visible = !self.overlay_handles.is_empty();
+        });
+    }
+    
+    /// Create the tray icon and hook its event channels up to the tray poll
+    /// Must run on the main thread; called again by the status bar's Reconnect button
+    fn connect_tray(&mut self) {
+        // Drop the old icon first so a retry never leaves two in the tray
+        self.tray_manager = None;
+        
+        match TrayFlyoutManager::new_with_channels(self.profiles.clone(), self.active_profile_name.clone()) {
+            Ok((tray, event_rx, menu_rx, profile_rx)) => {
+                // Store the exit menu ID
+                if let Ok(mut guard) = MENU_EXIT_ID.lock() {
+                    *guard = Some(tray.menu_item_exit.clone());
+                }
+                if let Ok(mut guard) = MENU_SETTINGS_ID.lock() {
+                    *guard = Some(tray.menu_item_settings.clone());
+                }
+                if let Ok(mut guard) = MENU_TOGGLE_CROSSHAIR_ID.lock() {
+                    *guard = Some(tray.menu_item_toggle_crosshair.clone());
+                }
+                // Store channels in globals
+                if let Ok(mut guard) = TRAY_EVENT_RX.lock() {
+                    *guard = Some(event_rx);
+                }
+                if let Ok(mut guard) = MENU_EVENT_RX.lock() {
+                    *guard = Some(menu_rx);
+                }
+                if let Ok(mut guard) = FLYOUT_PROFILE_RX.lock() {
+                    *guard = Some(profile_rx);
+                }
+                self.tray_manager = Some(tray);
+                println!("[GUI] Tray manager created successfully");
+            }
+            Err(e) => {
+                eprintln!("[GUI] Failed to create tray: {}", e);
+                self.status_message = format!("⚠️ Tray unavailable: {}", e);
+            }
+        }
+    }
+    
+    fn update_tray(&mut self) {
+        // Update tray with current profiles
+        if let Some(ref mut tray) = self.tray_manager {
+            tray.update_profiles(self.profiles.clone());
+            tray.set_active_profile(self.active_profile_name.clone());
+        }
+    }
+    
+    fn toggle_flyout(&mut self) {
+        if let Some(ref mut tray) = self.tray_manager {
+            if tray.is_flyout_visible() {
+                tray.hide_flyout();
+            } else {
+                if let Err(e) = tray.show_flyout() {
+                    eprintln!("[GUI] Failed to show flyout: {}", e);
+                }
+            }
+        }
+    }
+}
+
+impl Application for GameOptimizer {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Theme = Theme;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let data_dir = get_data_directory().ok();
+        let mut app_config = crate::config::load_config();
+        let mut app = GameOptimizer {
+            profiles: Vec::new(),
+            selected_profile_index: None,
+            edit_name: String::new(),
+            edit_description: text_editor::Content::new(),
+            edit_tags: String::new(),
+            edit_x_offset: "0".to_string(),
+            edit_y_offset: "0".to_string(),
+            edit_image_path: None,
+            edit_overlay_enabled: false,
+            edit_fan_speed_max: false,
+            edit_audio_device: None,
+            audio_devices: Vec::new(),
+            edit_power_plan: None,
+            power_plans: power::list_power_plans().unwrap_or_else(|e| {
+                println!("[GUI] Power plans unavailable: {}", e);
+                Vec::new()
+            }),
+            previous_power_plan: None,
+            priority_changes: Vec::new(),
+            edit_trim_memory: false,
+            edit_deactivate_on_exit: String::new(),
+            edit_activation_sound: None,
+            exit_watch: None,
+            edit_priority_rules: Vec::new(),
+            priority_rule_name: String::new(),
+            priority_rule_class: PriorityClass::High,
+            profile_sort: ProfileSort::Saved,
+            tag_filter: None,
+            edit_crosshair_kind: CrosshairKind::default(),
+            edit_crosshair_size: crosshair_shapes::DEFAULT_SIZE,
+            edit_crosshair_thickness: crosshair_shapes::DEFAULT_THICKNESS,
+            edit_crosshair_color: crosshair_shapes::DEFAULT_COLOR,
+            edit_crosshair_gap: crosshair_shapes::DEFAULT_GAP,
+            edit_crosshair_refresh: RefreshRate::AUTO,
+            edit_aggressive_topmost: true,
+            edit_topmost_interval: TopmostInterval::DEFAULT,
+            edit_topmost_on_foreground: false,
+            edit_hide_when_unfocused: false,
+            edit_game_process: String::new(),
+            edit_crosshair_layers: Vec::new(),
+            edit_crosshair_presets: Vec::new(),
+            edit_cycle_shortcut: String::new(),
+            process_selection: HashMap::new(),
+            running_processes: Vec::new(),
+            process_sampler: ProcessSampler::new(),
+            common_app_status: Vec::new(),
+            process_filter: String::new(),
+            quick_scan: None,
+            last_kill_report: None,
+            cycle_hotkey: None,
+            activation_flash: None,
+            status_message: "Welcome to Gaming Optimizer".to_string(),
+            data_dir,
+            active_profile_name: None,
+            overlay_handles: Vec::new(),
+            pre_center_offset: None,
+            positioning_mode: false,
+            click_through_pending: false,
+            tray_manager: None,  // Will be set by run() via Flags if we change approach
+            close_to_tray: app_config.close_to_tray,
+            show_notifications: app_config.show_notifications,
+            flyout_backdrop: app_config.flyout_backdrop,
+            lock_active_profile: app_config.lock_active_profile,
+            start_minimized: app_config.start_minimized,
+            mute_sounds: app_config.mute_sounds,
+            default_profile_on_startup: None,
+            confirm_before_killing: app_config.confirm_before_killing.clone(),
+            page: Page::Profiles,
+            log_lines: Vec::new(),
+            file_hovering: false,
+            missing_images: HashSet::new(),
+            welcome_apps: HashSet::new(),
+            welcome_crosshair: true,
+            copy_settings: None,
+        };
+        // First launch: no profiles.json has ever been written
+        let first_run = app.data_dir.as_ref().is_some_and(|dir| !dir.join("profiles.json").exists());
+        app.load_profiles_from_disk();
+        app.refresh_running_processes();
+        app.refresh_audio_devices();
+        
+        // Nothing is active in a fresh GUI session - don't let a stale config.json say otherwise
+        app.persist_active_state();
+        crate::ipc::spawn_status_server();
+        
+        // Create tray manager on main thread (inside iced's new)
+        app.connect_tray();
+        
+        // A chosen startup profile replaces the "nothing active" start, whatever was last active
+        if let Some(missing) = app_config.validate_startup_profile(app.profiles.iter().map(|p| p.name.as_str())) {
+            println!("[GUI] Startup profile '{}' no longer exists, starting with nothing active", missing);
+            app.update_config(|c| c.default_profile_on_startup = None);
+        }
+        app.default_profile_on_startup = app_config.default_profile_on_startup;
+        if let Some(name) = app.default_profile_on_startup.clone() {
+            println!("[GUI] Activating startup profile: {}", name);
+            app.activate_profile_by_name(&name);
+        }
+        
+        let mut command = Command::none();
+        if first_run && app.profiles.is_empty() {
+            app.page = Page::Welcome;
+            app.status_message = "Welcome! Set up your first profile".to_string();
+        } else {
+            // Reopen where the last session left off
+            if let Some(index) = app_config.last_selected_profile
+                .and_then(|name| app.profiles.iter().position(|p| p.name == name))
+            {
+                app.load_profile_to_edit(index);
+            }
+            app.page = Page::from_config_name(&app_config.last_page);
+            if app.page == Page::Logs {
+                app.reload_log_lines();
+                command = scrollable::snap_to(LOG_SCROLL_ID.clone(), scrollable::RelativeOffset::END);
+            }
+        }
+        
+        (app, command)
+    }
+
+    fn title(&self) -> String {
         String::from("Gaming Optimizer - Profile Manager")
     }
 
@@ -522,14 +1857,61 @@ impl Application for GameOptimizer {
         // Poll for tray events (faster polling for responsive click detection)
         struct TrayPoller;
         
-        iced::subscription::unfold(
+        let tray_poll = iced::subscription::unfold(
             std::any::TypeId::of::<TrayPoller>(),
             (),
             |_| async move {
                 std::thread::sleep(Duration::from_millis(50)); // 50ms for responsive clicks
                 (Message::TrayTick, ())
             }
-        )
+        );
+        
+        // Only sees keys a focused text input didn't capture, so typing isn't hijacked
+        let shortcuts = iced::keyboard::on_key_press(shortcut_message);
+        
+        // Close requests are handled in update() so the window can hide to the tray instead.
+        // Files dropped on the window become the crosshair image.
+        let window_events = iced::event::listen_with(|event, _status| match event {
+            iced::Event::Window(id, iced::window::Event::CloseRequested) if id == iced::window::Id::MAIN => {
+                Some(Message::WindowCloseRequested)
+            }
+            iced::Event::Window(_, iced::window::Event::FileHovered(_)) => Some(Message::ImageFileHovered),
+            iced::Event::Window(_, iced::window::Event::FilesHoveredLeft) => Some(Message::ImageFileHoverLeft),
+            iced::Event::Window(_, iced::window::Event::FileDropped(path)) => Some(Message::ImageFileDropped(path)),
+            _ => None,
+        });
+        
+        // Re-read the log file while the Logs page is open
+        let log_poll = if self.page == Page::Logs {
+            struct LogPoller;
+            iced::subscription::unfold(
+                std::any::TypeId::of::<LogPoller>(),
+                (),
+                |_| async move {
+                    std::thread::sleep(Duration::from_secs(1));
+                    (Message::LogTick, ())
+                }
+            )
+        } else {
+            Subscription::none()
+        };
+        
+        // Animate the sidebar pulse only while one is running
+        let flash_tick = if self.activation_flash.is_some() {
+            struct FlashTicker;
+            iced::subscription::unfold(
+                std::any::TypeId::of::<FlashTicker>(),
+                (),
+                |_| async move {
+                    std::thread::sleep(Duration::from_millis(40));
+                    (Message::FlashTick, ())
+                }
+            )
+        } else {
+            Subscription::none()
+        };
+        
+        Subscription::batch([tray_poll, shortcuts, window_events, log_poll, flash_tick])
     }
 
     fn update(&mut self, message: Message) -> Command<Message> {
@@ -543,84 +1925,424 @@ impl Application for GameOptimizer {
                     TrayAction::ProfileSelected(name) => {
                         return self.update(Message::TrayProfileSelected(name));
                     }
+                    TrayAction::ShowWindow => {
+                        return Command::batch([
+                            iced::window::change_mode(iced::window::Id::MAIN, iced::window::Mode::Windowed),
+                            iced::window::gain_focus(iced::window::Id::MAIN),
+                        ]);
+                    }
+                    TrayAction::ToggleCrosshair => {
+                        return self.update(Message::TrayToggleCrosshair);
+                    }
+                    TrayAction::CycleCrosshair => {
+                        return self.update(Message::CycleCrosshairPreset);
+                    }
+                    TrayAction::MenuItem(id) => {
+                        let profile = self.tray_manager.as_ref().and_then(|t| t.profile_for_menu_id(&id));
+                        if let Some(name) = profile {
+                            self.activate_profile_by_name(&name);
+                            // Re-sync check marks even if the activation was cancelled
+                            self.update_tray();
+                        }
+                    }
                     TrayAction::Exit => {
                         return self.update(Message::TrayExit);
                     }
                     _ => {}
                 }
+                
+                self.poll_positioning_offset();
+                self.poll_exit_watch();
             }
             
             Message::TrayProfileSelected(name) => {
                 self.activate_profile_by_name(&name);
             }
             
-            Message::TrayDeactivate => {
+            Message::DeactivateProfile | Message::TrayDeactivate => {
                 self.deactivate_profile();
             }
             
-            Message::TrayExit => {
-                // Clean exit
-                std::process::exit(0);
+            Message::TrayToggleCrosshair => {
+                self.toggle_active_crosshair();
             }
             
-            Message::ProfileNameChanged(name) => {
-                self.edit_name = name;
+            Message::CycleCrosshairPreset => {
+                self.cycle_active_crosshair();
             }
             
-            Message::ProfileSelected(index) => {
-                self.load_profile_to_edit(index);
-                self.status_message = format!("Editing profile: {}", self.edit_name);
+            Message::ShowPage(page) => {
+                self.page = page;
+                self.remember_view();
+                if page == Page::Logs {
+                    self.reload_log_lines();
+                    return scrollable::snap_to(LOG_SCROLL_ID.clone(), scrollable::RelativeOffset::END);
+                }
             }
             
-            Message::NewProfile => {
+            Message::WelcomeAppToggled(exe, checked) => {
+                if checked {
+                    self.welcome_apps.insert(exe);
+                } else {
+                    self.welcome_apps.remove(&exe);
+                }
+            }
+            
+            Message::WelcomeCrosshairToggled(enabled) => {
+                self.welcome_crosshair = enabled;
+            }
+            
+            Message::FinishWelcome => {
+                let mut profile = create_profile("Gaming".to_string());
+                profile.processes_to_kill = self.welcome_apps.iter().cloned().collect();
+                profile.processes_to_kill.sort();
+                profile.overlay_enabled = self.welcome_crosshair;
+                if self.welcome_crosshair {
+                    profile.crosshair_image_path = Some(assets::BUILTIN_DEFAULT_CROSSHAIR.to_string());
+                }
+                
+                // Written through the normal save path, so a later launch skips the wizard
+                self.profiles.push(profile);
+                self.save_profiles_to_disk();
+                self.update_tray();
+                self.load_profile_to_edit(self.profiles.len() - 1);
+                self.page = Page::Profiles;
+                self.status_message = "✅ Created your first profile: Gaming - press ⚡ ACTIVATE before you play".to_string();
+            }
+            
+            Message::FlashTick => {
+                if self.activation_flash.as_ref().is_some_and(|(_, started)| started.elapsed() >= ACTIVATION_FLASH) {
+                    self.activation_flash = None;
+                }
+            }
+            
+            Message::LogTick => {
+                // Follow new output only when there is any, so scrolling back isn't yanked away
+                if self.reload_log_lines() {
+                    return scrollable::snap_to(LOG_SCROLL_ID.clone(), scrollable::RelativeOffset::END);
+                }
+            }
+            
+            Message::CopyLogs => {
+                self.status_message = format!("📋 Copied {} log lines", self.log_lines.len());
+                return iced::clipboard::write(self.log_lines.join("\n"));
+            }
+            
+            Message::CopyKillReport => {
+                if let Some((ref name, ref report)) = self.last_kill_report {
+                    self.status_message = "📋 Copied the kill report".to_string();
+                    return iced::clipboard::write(format!("Gaming Optimizer - activated '{}'\n\n{}", name, report.to_text()));
+                }
+            }
+            
+            Message::OpenLogFolder => {
+                match logging::log_directory() {
+                    Ok(dir) => {
+                        if let Err(e) = open::that(&dir) {
+                            self.status_message = format!("❌ Failed to open log folder: {}", e);
+                        }
+                    }
+                    Err(e) => self.status_message = format!("❌ {}", e),
+                }
+            }
+            
+            Message::WindowCloseRequested => {
+                if self.close_to_tray {
+                    // Keep running so the tray, flyout and active profile stay alive
+                    println!("[GUI] Close requested - hiding to tray");
+                    return iced::window::change_mode(iced::window::Id::MAIN, iced::window::Mode::Hidden);
+                }
+                return self.update(Message::TrayExit);
+            }
+            
+            Message::CloseToTrayToggled(enabled) => {
+                self.close_to_tray = enabled;
+                self.update_config(|c| c.close_to_tray = enabled);
+            }
+            
+            Message::NotificationsToggled(enabled) => {
+                self.show_notifications = enabled;
+                self.update_config(|c| c.show_notifications = enabled);
+            }
+            
+            Message::FlyoutBackdropToggled(enabled) => {
+                self.flyout_backdrop = enabled;
+                self.update_config(|c| c.flyout_backdrop = enabled);
+            }
+            
+            Message::LockActiveProfileToggled(enabled) => {
+                self.lock_active_profile = enabled;
+                self.update_config(|c| c.lock_active_profile = enabled);
+            }
+            
+            Message::StartMinimizedToggled(enabled) => {
+                self.start_minimized = enabled;
+                self.update_config(|c| c.start_minimized = enabled);
+            }
+            
+            Message::StartupProfileSelected(label) => {
+                let name = (label != NO_STARTUP_PROFILE).then_some(label);
+                self.set_startup_profile(name);
+            }
+            
+            Message::ReconnectTray => {
+                self.connect_tray();
+                if self.tray_manager.is_some() {
+                    self.status_message = "📌 Tray reconnected".to_string();
+                }
+            }
+            
+            Message::TrayExit => {
+                // Clean exit - don't leave the user stuck on a profile's power plan
+                self.restore_power_plan();
+                std::process::exit(0);
+            }
+            
+            Message::ProfileNameChanged(name) => {
+                self.edit_name = name;
+            }
+            
+            Message::TagsChanged(tags) => {
+                self.edit_tags = tags;
+            }
+            
+            Message::DescriptionEdited(action) => {
+                self.edit_description.perform(action);
+            }
+            
+            Message::ProfileSelected(index) => {
+                self.page = Page::Profiles;
+                self.load_profile_to_edit(index);
+                self.refresh_missing_images();
+                self.remember_view();
+                self.status_message = format!("Editing profile: {}", self.edit_name);
+            }
+            
+            Message::NewProfile => {
+                self.page = Page::Profiles;
                 self.clear_edit_form();
+                self.remember_view();
                 self.status_message = "Creating new profile".to_string();
             }
             
             Message::SaveProfile => {
-                if self.edit_name.trim().is_empty() {
-                    self.status_message = "❌ Error: Profile name cannot be empty".to_string();
-                    return Command::none();
-                }
-                
-                let x_offset = self.edit_x_offset.parse().unwrap_or(0);
-                let y_offset = self.edit_y_offset.parse().unwrap_or(0);
-                
-                let profile = Profile {
-                    name: self.edit_name.clone(),
-                    processes_to_kill: self.get_selected_processes(),
-                    crosshair_image_path: self.edit_image_path.clone(),
-                    crosshair_x_offset: x_offset,
-                    crosshair_y_offset: y_offset,
-                    overlay_enabled: self.edit_overlay_enabled,
-                    fan_speed_max: self.edit_fan_speed_max,
-                };
-                
+                self.save_edit_form();
+            }
+            
+            Message::RevertProfile => {
                 if let Some(index) = self.selected_profile_index {
-                    self.profiles[index] = profile;
-                    self.status_message = format!("✅ Updated profile: {}", self.edit_name);
-                } else {
-                    self.profiles.push(profile);
-                    self.selected_profile_index = Some(self.profiles.len() - 1);
-                    self.status_message = format!("✅ Created profile: {}", self.edit_name);
+                    self.load_profile_to_edit(index);
+                    self.refresh_missing_images();
+                    self.status_message = format!("↩ Reverted unsaved changes to: {}", self.edit_name);
+                }
+            }
+            
+            Message::SaveAndReapply => {
+                if !self.selected_is_active() {
+                    return self.update(Message::SaveProfile);
                 }
                 
-                self.save_profiles_to_disk();
-                self.update_tray();
+                if self.save_edit_form() {
+                    // Undo the old priority changes only once the new rules are saved
+                    self.reset_active_priorities();
+                    if let Some(index) = self.selected_profile_index {
+                        self.reapply_active_profile(index);
+                    }
+                }
             }
             
             Message::DeleteProfile => {
                 if let Some(index) = self.selected_profile_index {
                     let name = self.profiles[index].name.clone();
+                    if !confirm_dialog("Delete profile", &format!("Delete profile '{}'?", name)) {
+                        return Command::none();
+                    }
                     self.profiles.remove(index);
                     self.clear_edit_form();
                     self.save_profiles_to_disk();
                     self.update_tray();
+                    if self.default_profile_on_startup.as_ref() == Some(&name) {
+                        self.set_startup_profile(None);
+                    }
                     self.status_message = format!("🗑️ Deleted profile: {}", name);
                 }
             }
             
+            Message::DuplicateProfile => {
+                if let Some(index) = self.selected_profile_index {
+                    let mut copy = self.profiles[index].clone();
+                    
+                    // Shortened if needed so the copy still fits the name length limit
+                    let mut name = name_with_suffix(&copy.name, " (copy)");
+                    let mut n = 2;
+                    while !self.profiles_name_unique(&name, None) {
+                        name = name_with_suffix(&copy.name, &format!(" (copy) {}", n));
+                        n += 1;
+                    }
+                    copy.name = name.clone();
+                    copy.last_activated = None;
+                    copy.activation_count = 0;
+                    
+                    self.profiles.push(copy);
+                    self.load_profile_to_edit(self.profiles.len() - 1);
+                    self.save_profiles_to_disk();
+                    self.update_tray();
+                    self.status_message = format!("📄 Duplicated profile as: {}", name);
+                }
+            }
+            
+            Message::OpenCopySettings => {
+                self.copy_settings = Some(CopySettingsForm {
+                    fields: CopyFields { crosshair: true, processes: false, fan: false },
+                    targets: HashSet::new(),
+                });
+            }
+            
+            Message::CloseCopySettings => {
+                self.copy_settings = None;
+            }
+            
+            Message::CopyFieldsChanged(fields) => {
+                if let Some(ref mut form) = self.copy_settings {
+                    form.fields = fields;
+                }
+            }
+            
+            Message::CopyTargetToggled(index, checked) => {
+                if let Some(ref mut form) = self.copy_settings {
+                    if checked {
+                        form.targets.insert(index);
+                    } else {
+                        form.targets.remove(&index);
+                    }
+                }
+            }
+            
+            Message::CopySettingsTo { targets, fields } => {
+                let Some(source_index) = self.selected_profile_index else {
+                    return Command::none();
+                };
+                // Copies what is saved, not unsaved edits in the form
+                let source = self.profiles[source_index].clone();
+                let mut copied = 0;
+                for index in targets.into_iter().filter(|&i| i != source_index) {
+                    if let Some(target) = self.profiles.get_mut(index) {
+                        target.copy_settings_from(&source, fields);
+                        copied += 1;
+                    }
+                }
+                
+                self.copy_settings = None;
+                self.save_profiles_to_disk();
+                self.update_tray();
+                
+                let groups: Vec<&str> = [(fields.crosshair, "crosshair"), (fields.processes, "processes"), (fields.fan, "fan")]
+                    .into_iter()
+                    .filter_map(|(on, name)| on.then_some(name))
+                    .collect();
+                self.status_message = format!("📋 Copied {} from '{}' to {} profile(s)", groups.join(", "), source.name, copied);
+            }
+            
+            Message::BackupProfiles => {
+                let path = rfd::FileDialog::new()
+                    .set_title("Back up all profiles")
+                    .add_filter("JSON", &["json"])
+                    .set_file_name(crate::profile::backup_file_name())
+                    .save_file();
+                
+                if let Some(path) = path {
+                    let app_config = crate::config::load_config();
+                    match crate::profile::export_all_profiles(&self.profiles, &app_config, &path) {
+                        Ok(_) => {
+                            self.status_message = format!("💾 Backed up {} profiles to {}", self.profiles.len(), path.display());
+                        }
+                        Err(e) => {
+                            self.status_message = format!("❌ Backup failed: {}", e);
+                        }
+                    }
+                }
+            }
+            
+            Message::RestoreProfiles => {
+                let Some(path) = rfd::FileDialog::new()
+                    .set_title("Restore profiles from backup")
+                    .add_filter("JSON", &["json"])
+                    .pick_file()
+                else {
+                    return Command::none();
+                };
+                
+                let (bundle, skipped) = match crate::profile::import_all_profiles(&path) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        self.status_message = format!("❌ Restore failed: {}", e);
+                        return Command::none();
+                    }
+                };
+                
+                let collisions: Vec<String> = bundle.profiles
+                    .iter()
+                    .filter(|p| !self.profiles_name_unique(&p.name, None))
+                    .map(|p| p.name.clone())
+                    .collect();
+                
+                // Yes = replace the existing profiles, No = keep both, Cancel = abort
+                let replace = if collisions.is_empty() {
+                    false
+                } else {
+                    let choice = rfd::MessageDialog::new()
+                        .set_level(rfd::MessageLevel::Warning)
+                        .set_title("Profiles already exist")
+                        .set_description(format!(
+                            "These profiles already exist: {}\n\nYes: replace them with the backup\nNo: keep both (backup copies get \"(restored)\")",
+                            collisions.join(", ")
+                        ))
+                        .set_buttons(rfd::MessageButtons::YesNoCancel)
+                        .show();
+                    match choice {
+                        rfd::MessageDialogResult::Yes => true,
+                        rfd::MessageDialogResult::No => false,
+                        _ => {
+                            self.status_message = "Restore cancelled".to_string();
+                            return Command::none();
+                        }
+                    }
+                };
+                
+                let restored = bundle.profiles.len();
+                crate::profile::merge_profiles(&mut self.profiles, bundle.profiles, replace);
+                
+                // The backup also carries config.json; replacing the user's settings is their call
+                let settings_restored = bundle.config.is_some_and(|backup_config| {
+                    confirm_dialog(
+                        "Restore settings",
+                        "The backup also holds your app settings (tray, notifications, startup profile, \
+                         flyout size, confirm list, ...).\n\nReplace the current settings with them?",
+                    ) && self.restore_settings(backup_config)
+                });
+                
+                self.clear_edit_form();
+                self.save_profiles_to_disk();
+                self.update_tray();
+                let mut status = format!("📂 Restored {} profiles", restored);
+                if settings_restored {
+                    status.push_str(" and the app settings");
+                }
+                if skipped > 0 {
+                    status.push_str(&format!(" ({} invalid skipped)", skipped));
+                }
+                // save_profiles_to_disk flagged these; the user re-picks their images
+                if !self.missing_images.is_empty() {
+                    status.push_str(&format!(" - {} need their crosshair image re-picked", self.missing_images.len()));
+                }
+                self.status_message = status;
+            }
+            
             Message::ActivateProfile => {
+                let target = self.selected_profile_index.map(|i| self.profiles[i].name.clone());
+                if target.is_some_and(|name| !self.switch_allowed(&name)) {
+                    return Command::none();
+                }
                 self.activate_current_profile();
             }
             
@@ -628,98 +2350,516 @@ impl Application for GameOptimizer {
                 self.process_selection.insert(process, enabled);
             }
             
+            Message::TabPressed { backwards } => {
+                if self.page != Page::Profiles {
+                    return Command::none();
+                }
+                return Command::widget(FindFocusedInput(None)).map(move |focused| {
+                    let current = focused.as_ref().and_then(EditorField::from_id);
+                    Message::FocusEditorField(EditorField::after(current, backwards))
+                });
+            }
+            
+            Message::FocusEditorField(field) => {
+                return text_input::focus(field.id());
+            }
+            
+            Message::SelectVisibleProcesses { skip_protected } => {
+                let visible = self.process_rows();
+                let names = bulk_selection(
+                    visible.iter().take(PROCESS_LIST_LIMIT).map(|row| row.exe_name),
+                    skip_protected,
+                );
+                let skipped = visible.len().min(PROCESS_LIST_LIMIT) - names.len();
+                
+                let count = names.len();
+                for name in names {
+                    self.process_selection.insert(name, true);
+                }
+                self.status_message = if skipped > 0 {
+                    format!("Selected {} processes ({} system/protected left out)", count, skipped)
+                } else {
+                    format!("Selected {} processes", count)
+                };
+            }
+            
             Message::RefreshProcesses => {
                 self.refresh_running_processes();
                 self.status_message = format!("🔄 Refreshed: {} processes found", self.running_processes.len());
             }
             
+            Message::QuickScan => {
+                self.refresh_running_processes();
+                let top = top_consumers(&self.running_processes, QUICK_SCAN_RESULTS);
+                self.status_message = format!("🔎 Scanned {} processes", self.running_processes.len());
+                self.quick_scan = Some(top);
+            }
+            
+            Message::DismissQuickScan => {
+                self.quick_scan = None;
+            }
+            
             Message::ProcessFilterChanged(filter) => {
                 self.process_filter = filter;
             }
             
             Message::CrosshairOffsetXChanged(value) => {
-                self.edit_x_offset = value;
+                self.edit_x_offset = sanitize_int_input(&value, true);
             }
             
             Message::CrosshairOffsetYChanged(value) => {
-                self.edit_y_offset = value;
+                self.edit_y_offset = sanitize_int_input(&value, true);
             }
             
             Message::CrosshairMoveUp => {
-                let current: i32 = self.edit_y_offset.parse().unwrap_or(0);
-                self.edit_y_offset = (current - 1).to_string();
-                self.update_live_overlay();
+                self.nudge_crosshair(0, -1);
             }
             
             Message::CrosshairMoveDown => {
-                let current: i32 = self.edit_y_offset.parse().unwrap_or(0);
-                self.edit_y_offset = (current + 1).to_string();
-                self.update_live_overlay();
+                self.nudge_crosshair(0, 1);
             }
             
             Message::CrosshairMoveLeft => {
-                let current: i32 = self.edit_x_offset.parse().unwrap_or(0);
-                self.edit_x_offset = (current - 1).to_string();
-                self.update_live_overlay();
+                self.nudge_crosshair(-1, 0);
             }
             
             Message::CrosshairMoveRight => {
-                let current: i32 = self.edit_x_offset.parse().unwrap_or(0);
-                self.edit_x_offset = (current + 1).to_string();
-                self.update_live_overlay();
+                self.nudge_crosshair(1, 0);
+            }
+            
+            Message::CrosshairMoveBy(dx, dy) => {
+                self.nudge_crosshair(dx, dy);
             }
             
             Message::CrosshairCenter => {
+                let x: i32 = self.edit_x_offset.parse().unwrap_or(0);
+                let y: i32 = self.edit_y_offset.parse().unwrap_or(0);
+                if x != 0 || y != 0 {
+                    self.pre_center_offset = Some((x, y));
+                    self.status_message = format!("Crosshair centered (↺ Restore returns to X={}, Y={})", x, y);
+                } else {
+                    self.status_message = "Crosshair centered".to_string();
+                }
+                self.edit_x_offset = "0".to_string();
+                self.edit_y_offset = "0".to_string();
+                self.update_live_overlay();
+            }
+            
+            Message::CrosshairRestore => {
+                if let Some((x, y)) = self.pre_center_offset.take() {
+                    self.edit_x_offset = x.to_string();
+                    self.edit_y_offset = y.to_string();
+                    self.status_message = format!("Crosshair restored to X={}, Y={}", x, y);
+                    self.update_live_overlay();
+                }
+            }
+            
+            Message::PreviewKill => {
+                // Dry run against the edit form, so unsaved selections can be checked too
+                let report = kill_processes(&self.get_selected_processes(), true);
+                let mut parts = Vec::new();
+                if !report.killed.is_empty() {
+                    parts.push(format!("Would kill: {}", report.killed.join(", ")));
+                }
+                if !report.not_found.is_empty() {
+                    parts.push(format!("Not running: {}", report.not_found.join(", ")));
+                }
+                if !report.blocklist_skipped.is_empty() {
+                    let skipped: Vec<String> = report.blocklist_skipped
+                        .iter()
+                        .map(|(name, reason)| format!("{} ({})", name, reason))
+                        .collect();
+                    parts.push(format!("Protected: {}", skipped.join(", ")));
+                }
+                self.status_message = if parts.is_empty() {
+                    "👁 Preview: no processes selected".to_string()
+                } else {
+                    format!("👁 Preview (nothing killed): {}", parts.join(" | "))
+                };
+            }
+            
+            Message::ResetCrosshair => {
+                // Crosshair fields only - the image and the rest of the profile are kept
                 self.edit_x_offset = "0".to_string();
                 self.edit_y_offset = "0".to_string();
-                self.status_message = "Crosshair centered".to_string();
+                self.pre_center_offset = None;
+                self.edit_crosshair_kind = CrosshairKind::default();
+                self.edit_crosshair_size = crosshair_shapes::DEFAULT_SIZE;
+                self.edit_crosshair_thickness = crosshair_shapes::DEFAULT_THICKNESS;
+                self.edit_crosshair_color = crosshair_shapes::DEFAULT_COLOR;
+                self.edit_crosshair_gap = crosshair_shapes::DEFAULT_GAP;
+                self.edit_crosshair_refresh = RefreshRate::AUTO;
+                self.status_message = "Crosshair settings reset to defaults".to_string();
                 self.update_live_overlay();
             }
             
+            Message::PositioningModeToggled(enabled) => {
+                self.set_positioning_mode(enabled);
+            }
+            
             Message::OverlayEnabledToggled(enabled) => {
                 self.edit_overlay_enabled = enabled;
             }
             
-            Message::FanSpeedMaxToggled(enabled) => {
-                self.edit_fan_speed_max = enabled;
+            Message::CrosshairKindSelected(kind) => {
+                self.edit_crosshair_kind = kind;
+                self.update_live_overlay();
             }
             
-            Message::SelectImage => {
-                match open_image_picker() {
-                    Ok(path) => {
-                        match validate_crosshair_image(&path) {
-                            Ok(_) => {
-                                let path_str = path.to_string_lossy().to_string();
-                                self.edit_image_path = Some(path_str.clone());
-                                self.status_message = format!("📁 Selected image: {}", path_str);
-                            }
-                            Err(e) => {
-                                self.status_message = format!("❌ Invalid image: {}", e);
-                            }
+            Message::CrosshairSizeChanged(size) => {
+                self.edit_crosshair_size = size;
+                self.edit_crosshair_thickness = self.edit_crosshair_thickness.min(size);
+                self.edit_crosshair_gap = self.edit_crosshair_gap.min(size - 1);
+            }
+            
+            Message::CrosshairThicknessChanged(thickness) => {
+                self.edit_crosshair_thickness = thickness.min(self.edit_crosshair_size);
+            }
+            
+            Message::CrosshairGapChanged(gap) => {
+                self.edit_crosshair_gap = gap.min(self.edit_crosshair_size - 1);
+            }
+            
+            Message::CrosshairColorSelected(preset) => {
+                self.edit_crosshair_color = preset.rgb;
+                self.update_live_overlay();
+            }
+            
+            Message::CrosshairRefreshSelected(refresh) => {
+                self.edit_crosshair_refresh = refresh;
+                self.update_live_overlay();
+            }
+            
+            Message::AggressiveTopmostToggled(enabled) => {
+                self.edit_aggressive_topmost = enabled;
+                self.update_live_overlay();
+            }
+            
+            Message::TopmostIntervalSelected(interval) => {
+                self.edit_topmost_interval = interval;
+                self.update_live_overlay();
+            }
+            
+            Message::TopmostOnForegroundToggled(enabled) => {
+                self.edit_topmost_on_foreground = enabled;
+                self.update_live_overlay();
+            }
+            
+            Message::HideWhenUnfocusedToggled(enabled) => {
+                self.edit_hide_when_unfocused = enabled;
+                self.update_live_overlay();
+            }
+            
+            Message::GameProcessChanged(process) => {
+                self.edit_game_process = process;
+            }
+            
+            Message::CrosshairShapeCommitted => {
+                // Sliders only restart the overlay on release, not on every step
+                self.update_live_overlay();
+            }
+            
+            Message::AddCrosshairLayer => {
+                if let Ok(path) = open_image_picker() {
+                    match validate_crosshair_image(&path) {
+                        Ok(_) => {
+                            let path_str = path.to_string_lossy().to_string();
+                            self.edit_crosshair_layers.push(CrosshairLayer::new(path_str.clone()));
+                            self.status_message = format!("➕ Added crosshair layer: {}", path_str);
+                            self.update_live_overlay();
+                        }
+                        Err(e) => {
+                            self.status_message = format!("❌ Invalid image: {}", e);
                         }
                     }
-                    Err(_) => {}
                 }
             }
             
-            Message::ClearImage => {
-                self.edit_image_path = None;
-                self.status_message = "Cleared crosshair image".to_string();
+            Message::AddCrosshairPreset => {
+                let (Ok(x_offset), Ok(y_offset)) = (self.edit_x_offset.parse(), self.edit_y_offset.parse()) else {
+                    self.status_message = "❌ Error: Crosshair offsets must be whole numbers".to_string();
+                    return Command::none();
+                };
+                let shape = self.edit_crosshair_shape();
+                let preset = CrosshairPreset {
+                    name: format!("Preset {}", self.edit_crosshair_presets.len() + 1),
+                    image_path: self.edit_image_path.clone(),
+                    kind: shape.kind,
+                    size: shape.size,
+                    thickness: shape.thickness,
+                    color: shape.color,
+                    gap: shape.gap,
+                    x_offset,
+                    y_offset,
+                };
+                self.status_message = format!("➕ Saved the current crosshair as {}", preset.name);
+                self.edit_crosshair_presets.push(preset);
             }
-        }
-        
-        Command::none()
-    }
-
-    fn view(&self) -> Element<'_, Message> {
-        // Left panel - Profile list
-        let mut profile_list = Column::new()
-            .spacing(5)
-            .padding(10)
-            .push(Text::new("📋 Profiles").size(20))
-            .push(Space::new(Length::Fill, Length::Fixed(10.0)));
+            
+            Message::UseCrosshairPreset(index) => {
+                if let Some(preset) = self.edit_crosshair_presets.get(index).cloned() {
+                    self.apply_preset_to_edit_form(&preset);
+                    self.update_live_overlay();
+                }
+            }
+            
+            Message::RemoveCrosshairPreset(index) => {
+                if index < self.edit_crosshair_presets.len() {
+                    self.edit_crosshair_presets.remove(index);
+                }
+            }
+            
+            Message::CycleShortcutChanged(shortcut) => {
+                self.edit_cycle_shortcut = shortcut;
+            }
+            
+            Message::RemoveCrosshairLayer(index) => {
+                if index < self.edit_crosshair_layers.len() {
+                    self.edit_crosshair_layers.remove(index);
+                    self.update_live_overlay();
+                }
+            }
+            
+            Message::CrosshairLayerXChanged(index, x) => {
+                if let Some(layer) = self.edit_crosshair_layers.get_mut(index) {
+                    layer.x_offset = x;
+                }
+            }
+            
+            Message::CrosshairLayerYChanged(index, y) => {
+                if let Some(layer) = self.edit_crosshair_layers.get_mut(index) {
+                    layer.y_offset = y;
+                }
+            }
+            
+            Message::CrosshairLayerScaleChanged(index, scale) => {
+                if let Some(layer) = self.edit_crosshair_layers.get_mut(index) {
+                    layer.scale_percent = scale;
+                }
+            }
+            
+            Message::CrosshairLayerOpacityChanged(index, opacity) => {
+                if let Some(layer) = self.edit_crosshair_layers.get_mut(index) {
+                    layer.opacity_percent = opacity;
+                }
+            }
+            
+            Message::TrimMemoryToggled(enabled) => {
+                self.edit_trim_memory = enabled;
+            }
+            
+            Message::DeactivateOnExitChanged(process) => {
+                self.edit_deactivate_on_exit = process;
+            }
+            
+            Message::ProfileSortSelected(sort) => {
+                self.profile_sort = sort;
+            }
+            
+            Message::TagFilterSelected(tag) => {
+                self.tag_filter = (tag != ALL_TAGS).then_some(tag);
+            }
+            
+            Message::ResetUsageStats(index) => {
+                if let Some(name) = self.profiles.get(index).map(|p| p.name.clone()) {
+                    if !confirm_dialog("Reset usage", &format!("Reset usage stats for '{}'?", name)) {
+                        return Command::none();
+                    }
+                    self.profiles[index].activation_count = 0;
+                    self.profiles[index].last_activated = None;
+                    self.save_profiles_to_disk();
+                    self.status_message = format!("Reset usage stats for: {}", name);
+                }
+            }
+            
+            Message::PriorityNameChanged(name) => {
+                self.priority_rule_name = name;
+            }
+            
+            Message::PriorityClassSelected(class) => {
+                self.priority_rule_class = class;
+            }
+            
+            Message::AddPriorityRule => {
+                let name = self.priority_rule_name.trim().to_string();
+                if name.is_empty() {
+                    self.status_message = "Enter a process name for the priority rule".to_string();
+                } else if protection_reason(&name).is_some() {
+                    self.status_message = format!("{} is protected and can't be re-prioritized", name);
+                } else {
+                    // One rule per process - replace an existing one
+                    self.edit_priority_rules.retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+                    self.edit_priority_rules.push((name, self.priority_rule_class));
+                    self.priority_rule_name.clear();
+                }
+            }
+            
+            Message::RemovePriorityRule(index) => {
+                if index < self.edit_priority_rules.len() {
+                    self.edit_priority_rules.remove(index);
+                }
+            }
+            
+            Message::FanSpeedMaxToggled(enabled) => {
+                self.edit_fan_speed_max = enabled;
+            }
+            
+            Message::AudioDeviceSelected(name) => {
+                self.edit_audio_device = if name == KEEP_AUDIO_DEVICE { None } else { Some(name) };
+            }
+            
+            Message::ActivationSoundSelected(label) => {
+                // The profile's own file keeps its value when re-selected
+                if label == NO_SOUND {
+                    self.edit_activation_sound = None;
+                } else if let Some(cue) = sound::builtin_cue(&label) {
+                    self.edit_activation_sound = Some(cue);
+                }
+            }
+            
+            Message::ChooseActivationSound => {
+                let Some(path) = rfd::FileDialog::new()
+                    .set_title("Choose an activation sound")
+                    .add_filter("WAV sound", &["wav"])
+                    .pick_file()
+                else {
+                    return Command::none();
+                };
+                
+                let cue = path.to_string_lossy().to_string();
+                match sound::validate_cue(&cue) {
+                    Ok(()) => {
+                        self.status_message = format!("🔔 Activation sound: {}", sound::cue_label(&cue));
+                        self.edit_activation_sound = Some(cue);
+                    }
+                    Err(e) => self.status_message = format!("❌ {}", e),
+                }
+            }
+            
+            Message::TestActivationSound => {
+                if let Some(ref cue) = self.edit_activation_sound {
+                    sound::play(cue);
+                }
+            }
+            
+            Message::MuteSoundsToggled(muted) => {
+                self.mute_sounds = muted;
+                self.update_config(|c| c.mute_sounds = muted);
+            }
+            
+            Message::PowerPlanSelected(label) => {
+                self.edit_power_plan = if label == KEEP_POWER_PLAN {
+                    None
+                } else {
+                    self.power_plans.iter().find(|p| p.name == label).map(|p| p.guid.clone())
+                        .or_else(|| self.edit_power_plan.clone())
+                };
+            }
+            
+            Message::RefreshAudioDevices => {
+                self.refresh_audio_devices();
+                self.status_message = format!("Found {} audio output devices", self.audio_devices.len());
+            }
+            
+            Message::SelectImage => {
+                match open_image_picker() {
+                    Ok(path) => {
+                        match validate_crosshair_image(&path) {
+                            Ok(_) => {
+                                let path_str = path.to_string_lossy().to_string();
+                                self.edit_image_path = Some(path_str.clone());
+                                self.status_message = format!("📁 Selected image: {}", path_str);
+                            }
+                            Err(e) => {
+                                self.status_message = format!("❌ Invalid image: {}", e);
+                            }
+                        }
+                    }
+                    Err(_) => {}
+                }
+            }
+            
+            Message::UseBuiltinImage => {
+                self.edit_image_path = Some(assets::BUILTIN_DEFAULT_CROSSHAIR.to_string());
+                self.status_message = "📁 Using the built-in crosshair image".to_string();
+            }
+            
+            Message::RevealCrosshairImage => {
+                if let Some(ref path) = self.edit_image_path {
+                    if let Err(e) = reveal_in_explorer(std::path::Path::new(path)) {
+                        self.status_message = format!("❌ {}", e);
+                    }
+                }
+            }
+            
+            Message::ClearImage => {
+                self.edit_image_path = None;
+                self.status_message = "Cleared crosshair image".to_string();
+            }
+            
+            Message::ImageFileHovered => {
+                self.file_hovering = true;
+            }
+            
+            Message::ImageFileHoverLeft => {
+                self.file_hovering = false;
+            }
+            
+            Message::ImageFileDropped(path) => {
+                self.file_hovering = false;
+                if self.page != Page::Profiles {
+                    self.status_message = "Open a profile to drop a crosshair image on it".to_string();
+                    return Command::none();
+                }
+                
+                // Same validation as the file picker
+                match validate_crosshair_image(&path) {
+                    Ok(_) => {
+                        let path_str = path.to_string_lossy().to_string();
+                        self.edit_image_path = Some(path_str.clone());
+                        self.status_message = format!("📁 Dropped image: {}", path_str);
+                    }
+                    Err(e) => {
+                        self.status_message = format!("❌ Invalid image: {}", e);
+                    }
+                }
+            }
+        }
+        
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        // Left panel - Profile list
+        let mut profile_list = Column::new()
+            .spacing(5)
+            .padding(10)
+            .push(Text::new("📋 Profiles").size(20))
+            .push(
+                PickList::new(&ProfileSort::ALL[..], Some(self.profile_sort), Message::ProfileSortSelected)
+                    .text_size(12)
+                    .width(Length::Fill)
+            );
+        
+        let tags = self.all_tags();
+        if !tags.is_empty() {
+            let mut options = vec![ALL_TAGS.to_string()];
+            options.extend(tags);
+            profile_list = profile_list.push(
+                PickList::new(
+                    options,
+                    Some(self.tag_filter.clone().unwrap_or_else(|| ALL_TAGS.to_string())),
+                    Message::TagFilterSelected
+                )
+                .text_size(12)
+                .width(Length::Fill)
+            );
+        }
+        profile_list = profile_list.push(Space::new(Length::Fill, Length::Fixed(10.0)));
         
-        for (i, profile) in self.profiles.iter().enumerate() {
+        let now = unix_now();
+        for i in self.sorted_profile_indices() {
+            let profile = &self.profiles[i];
             let is_selected = self.selected_profile_index == Some(i);
             let is_active = self.active_profile_name.as_ref() == Some(&profile.name);
             
@@ -731,11 +2871,47 @@ impl Application for GameOptimizer {
                 profile.name.clone()
             };
             
+            let mut details = Column::new().push(Text::new(label));
+            if self.missing_images.contains(&profile.name) {
+                details = details.push(Text::new("⚠ Crosshair image missing").size(11));
+            }
+            if let Some(summary) = profile.description_summary() {
+                details = details.push(Text::new(summary.to_string()).size(11));
+            }
+            if !profile.tags.is_empty() {
+                let chips = profile.tags.iter().fold(Row::new().spacing(3), |row, tag| {
+                    row.push(
+                        Container::new(Text::new(tag.clone()).size(10))
+                            .style(styles::tag_chip())
+                            .padding([1, 6])
+                    )
+                });
+                details = details.push(chips);
+            }
+            
+            let usage = match profile.last_activated {
+                Some(timestamp) => format!(
+                    "{} · used {}×",
+                    format_relative_time(timestamp, now),
+                    profile.activation_count
+                ),
+                None => "never used".to_string(),
+            };
+            
+            let mut button = Button::new(details.push(Text::new(usage).size(11)))
+                .on_press(Message::ProfileSelected(i))
+                .width(Length::Fill)
+                .padding(8);
+            if let Some((_, started)) = self.activation_flash.as_ref().filter(|(name, _)| name == &profile.name) {
+                // Fades from full highlight to nothing over ACTIVATION_FLASH
+                let progress = started.elapsed().as_secs_f32() / ACTIVATION_FLASH.as_secs_f32();
+                button = button.style(styles::activation_flash(1.0 - progress.min(1.0)));
+            }
+            
+            // Right-click resets the usage stats
             profile_list = profile_list.push(
-                Button::new(Text::new(label))
-                    .on_press(Message::ProfileSelected(i))
-                    .width(Length::Fill)
-                    .padding(8)
+                iced::widget::mouse_area(button)
+                    .on_right_press(Message::ResetUsageStats(i))
             );
         }
         
@@ -746,6 +2922,75 @@ impl Application for GameOptimizer {
                     .on_press(Message::NewProfile)
                     .width(Length::Fill)
                     .padding(10)
+            )
+            .push(
+                Row::new()
+                    .spacing(5)
+                    .push(
+                        Button::new(Text::new("💾 Backup").size(12))
+                            .on_press(Message::BackupProfiles)
+                            .width(Length::Fill)
+                            .padding(6)
+                    )
+                    .push(
+                        Button::new(Text::new("📂 Restore").size(12))
+                            .on_press(Message::RestoreProfiles)
+                            .width(Length::Fill)
+                            .padding(6)
+                    )
+            )
+            .push(
+                if self.page == Page::Logs {
+                    Button::new(Text::new("✏️ Back to Profiles").size(12))
+                        .on_press(Message::ShowPage(Page::Profiles))
+                        .width(Length::Fill)
+                        .padding(6)
+                } else {
+                    Button::new(Text::new("📜 Logs").size(12))
+                        .on_press(Message::ShowPage(Page::Logs))
+                        .width(Length::Fill)
+                        .padding(6)
+                }
+            )
+            .push(
+                Checkbox::new("Close to tray", self.close_to_tray)
+                    .on_toggle(Message::CloseToTrayToggled)
+                    .text_size(12)
+            )
+            .push(
+                Checkbox::new("Notifications", self.show_notifications)
+                    .on_toggle(Message::NotificationsToggled)
+                    .text_size(12)
+            )
+            .push(
+                Checkbox::new("Acrylic tray flyout", self.flyout_backdrop)
+                    .on_toggle(Message::FlyoutBackdropToggled)
+                    .text_size(12)
+            )
+            .push(
+                Checkbox::new("Lock active profile", self.lock_active_profile)
+                    .on_toggle(Message::LockActiveProfileToggled)
+                    .text_size(12)
+            )
+            .push(
+                Checkbox::new("Start minimized", self.start_minimized)
+                    .on_toggle(Message::StartMinimizedToggled)
+                    .text_size(12)
+            )
+            .push(
+                Checkbox::new("Mute sound cues", self.mute_sounds)
+                    .on_toggle(Message::MuteSoundsToggled)
+                    .text_size(12)
+            )
+            .push(Text::new("On startup").size(12))
+            .push(
+                PickList::new(
+                    self.startup_profile_options(),
+                    Some(self.default_profile_on_startup.clone().unwrap_or_else(|| NO_STARTUP_PROFILE.to_string())),
+                    Message::StartupProfileSelected
+                )
+                .text_size(12)
+                .width(Length::Fill)
             );
         
         let left_panel = Container::new(
@@ -756,15 +3001,47 @@ impl Application for GameOptimizer {
         .padding(10);
         
         // Right panel - Edit form
+        let unsaved = self.has_unsaved_changes();
         let edit_section = Column::new()
             .spacing(15)
             .padding(20)
-            .push(Text::new("✏️ Edit Profile").size(24))
+            .push(
+                Row::new()
+                    .spacing(15)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("✏️ Edit Profile").size(24))
+                    .push(
+                        if unsaved {
+                            Text::new("● Unsaved changes").size(12).style(styles::unsaved_hint())
+                        } else {
+                            Text::new("")
+                        }
+                    )
+            )
             
             .push(Text::new("Profile Name"))
             .push(
                 TextInput::new("Enter profile name...", &self.edit_name)
+                    .id(EditorField::Name.id())
                     .on_input(Message::ProfileNameChanged)
+                    .on_submit(Message::SaveProfile)
+                    .padding(10)
+                    .width(Length::Fill)
+            )
+            
+            .push(Text::new("Notes"))
+            .push(
+                TextEditor::new(&self.edit_description)
+                    .on_action(Message::DescriptionEdited)
+                    .height(Length::Fixed(80.0))
+                    .padding(10)
+            )
+            
+            .push(Text::new("Tags"))
+            .push(
+                TextInput::new("Comma-separated, e.g. FPS, Streaming", &self.edit_tags)
+                    .id(EditorField::Tags.id())
+                    .on_input(Message::TagsChanged)
                     .padding(10)
                     .width(Length::Fill)
             )
@@ -786,79 +3063,237 @@ impl Application for GameOptimizer {
                     )
             )
             
-            .push(Space::new(Length::Fill, Length::Fixed(10.0)))
-            
             .push(
                 Row::new()
-                    .spacing(10)
+                    .spacing(20)
                     .align_items(Alignment::Center)
-                    .push(Text::new("🔪 Processes to Kill").size(18))
+                    .push(Text::new("🔊 Audio Output").size(18))
                     .push(
-                        Button::new(Text::new("🔄 Refresh"))
-                            .on_press(Message::RefreshProcesses)
+                        PickList::new(
+                            self.audio_device_options(),
+                            Some(self.edit_audio_device.clone().unwrap_or_else(|| KEEP_AUDIO_DEVICE.to_string())),
+                            Message::AudioDeviceSelected
+                        )
+                        .width(Length::Fixed(300.0))
+                    )
+                    .push(
+                        Button::new(Text::new("⟳").size(14))
+                            .on_press(Message::RefreshAudioDevices)
                             .padding(5)
                     )
             )
-            .push(Text::new("Select running applications to close when activating:").size(12))
+            
             .push(
-                TextInput::new("Filter processes...", &self.process_filter)
-                    .on_input(Message::ProcessFilterChanged)
-                    .padding(8)
-                    .width(Length::Fill)
+                Row::new()
+                    .spacing(20)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("🔋 Power Plan").size(18))
+                    .push(
+                        PickList::new(
+                            self.power_plan_options(),
+                            Some(self.power_plan_label(&self.edit_power_plan)),
+                            Message::PowerPlanSelected
+                        )
+                        .width(Length::Fixed(300.0))
+                    )
             )
-            .push(self.render_process_selector())
-            
-            .push(Space::new(Length::Fill, Length::Fixed(10.0)))
             
-            .push(Text::new("🎯 Crosshair Overlay").size(18))
-            .push(Text::new("Crosshair will be centered on screen. Use arrows for pixel-perfect adjustment.").size(12))
-            
-            // Image selection row
             .push(
                 Row::new()
-                    .spacing(10)
+                    .spacing(20)
                     .align_items(Alignment::Center)
+                    .push(Text::new("🔔 Activation Sound").size(18))
                     .push(
-                        Button::new(Text::new("📁 Select Image"))
-                            .on_press(Message::SelectImage)
-                            .padding(10)
+                        PickList::new(
+                            self.activation_sound_options(),
+                            Some(self.edit_activation_sound.as_deref().map(sound::cue_label).unwrap_or_else(|| NO_SOUND.to_string())),
+                            Message::ActivationSoundSelected
+                        )
+                        .width(Length::Fixed(200.0))
                     )
                     .push(
-                        if self.edit_image_path.is_some() {
-                            Button::new(Text::new("❌ Clear"))
-                                .on_press(Message::ClearImage)
-                                .padding(10)
-                        } else {
-                            Button::new(Text::new("❌ Clear")).padding(10)
-                        }
+                        Button::new(Text::new("📁 WAV...").size(14))
+                            .on_press(Message::ChooseActivationSound)
+                            .padding(5)
                     )
                     .push(
-                        if let Some(ref path) = self.edit_image_path {
-                            Text::new(format!("✓ {}", path.split('\\').last().unwrap_or(path))).size(12)
+                        if self.edit_activation_sound.is_some() {
+                            Button::new(Text::new("▶ Test").size(14))
+                                .on_press(Message::TestActivationSound)
+                                .padding(5)
                         } else {
-                            Text::new("No image (100x100 PNG recommended)").size(12)
+                            Button::new(Text::new("▶ Test").size(14)).padding(5)
                         }
                     )
             )
             
-            // Crosshair adjustment box
             .push(
-                Container::new(
-                    Column::new()
-                        .spacing(5)
-                        .align_items(Alignment::Center)
-                        .push(Text::new("Position Adjustment").size(14))
-                        .push(
-                            Row::new()
-                                .spacing(10)
-                                .align_items(Alignment::Center)
-                                .push(Space::new(Length::Fixed(40.0), Length::Shrink))
-                                .push(
-                                    Button::new(Text::new("▲").size(16))
-                                        .on_press(Message::CrosshairMoveUp)
-                                        .padding(8)
-                                        .width(Length::Fixed(40.0))
-                                )
+                Row::new()
+                    .spacing(20)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("🧹 Free RAM").size(18))
+                    .push(
+                        Toggler::new(
+                            Some("Trim background working sets when active".to_string()),
+                            self.edit_trim_memory,
+                            Message::TrimMemoryToggled
+                        )
+                        .width(Length::Shrink)
+                    )
+            )
+            
+            .push(
+                Row::new()
+                    .spacing(20)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("🏁 Auto-Deactivate").size(18))
+                    .push(
+                        TextInput::new("When this process exits, e.g. cs2.exe", &self.edit_deactivate_on_exit)
+                            .id(EditorField::DeactivateOnExit.id())
+                            .on_input(Message::DeactivateOnExitChanged)
+                            .padding(8)
+                            .width(Length::Fixed(300.0))
+                    )
+            )
+            
+            .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+            
+            .push(Text::new("⚡ Process Priority").size(18))
+            .push(self.render_priority_rules())
+            
+            .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+            
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("🔪 Processes to Kill").size(18))
+                    .push(
+                        Button::new(Text::new("🔄 Refresh"))
+                            .on_press(Message::RefreshProcesses)
+                            .padding(5)
+                    )
+                    .push(
+                        Button::new(Text::new("🔎 What's slowing me down?"))
+                            .on_press(Message::QuickScan)
+                            .padding(5)
+                    )
+            )
+            .push(self.render_quick_scan())
+            .push(Text::new("Select running applications to close when activating:").size(12))
+            .push(
+                TextInput::new("Filter processes...", &self.process_filter)
+                    .id(EditorField::ProcessFilter.id())
+                    .on_input(Message::ProcessFilterChanged)
+                    .padding(8)
+                    .width(Length::Fill)
+            )
+            .push(self.render_process_selector())
+            .push(Text::new("Common Apps").size(14))
+            .push(Text::new("Pre-select known apps even when they aren't open yet:").size(12))
+            .push(self.render_common_apps())
+            
+            .push(Space::new(Length::Fill, Length::Fixed(10.0)))
+            
+            .push(Text::new("🎯 Crosshair Overlay").size(18))
+            .push(Text::new("Crosshair will be centered on screen. Use arrows for pixel-perfect adjustment.").size(12))
+            .push(
+                if self.file_hovering {
+                    Text::new("⬇ Drop the image to use it as this profile's crosshair").size(14)
+                } else {
+                    Text::new("Tip: drag an image file onto the window to use it").size(12)
+                }
+            )
+            
+            // Image selection row
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Button::new(Text::new("📁 Select Image"))
+                            .on_press(Message::SelectImage)
+                            .padding(10)
+                    )
+                    .push(
+                        Button::new(Text::new("⭐ Built-in"))
+                            .on_press(Message::UseBuiltinImage)
+                            .padding(10)
+                    )
+                    .push(
+                        if self.edit_image_path.is_some() {
+                            Button::new(Text::new("❌ Clear"))
+                                .on_press(Message::ClearImage)
+                                .padding(10)
+                        } else {
+                            Button::new(Text::new("❌ Clear")).padding(10)
+                        }
+                    )
+                    .push(
+                        if let Some(ref path) = self.edit_image_path {
+                            let file_name = path.split('\\').last().unwrap_or(path);
+                            if self.edit_image_missing() {
+                                Text::new(format!("⚠ missing: {}", file_name)).size(12)
+                            } else {
+                                Text::new(format!("✓ {}", file_name)).size(12)
+                            }
+                        } else {
+                            Text::new("No image - the shape below is drawn instead").size(12)
+                        }
+                    )
+                    .push(
+                        // Built-in images live inside the exe, so there's no folder to show
+                        if self.edit_image_missing() {
+                            Button::new(Text::new("🔁 Re-pick image").size(12))
+                                .on_press(Message::SelectImage)
+                                .padding(5)
+                        } else if self.edit_image_path.as_deref().is_some_and(|p| !assets::is_builtin(p)) {
+                            Button::new(Text::new("📂 Show in folder").size(12))
+                                .on_press(Message::RevealCrosshairImage)
+                                .padding(5)
+                        } else {
+                            Button::new(Text::new("📂 Show in folder").size(12)).padding(5)
+                        }
+                    )
+                    .push(Space::new(Length::Fill, Length::Shrink))
+                    .push(Text::new("Refresh").size(12))
+                    .push(
+                        PickList::new(&RefreshRate::OPTIONS[..], Some(self.edit_crosshair_refresh), Message::CrosshairRefreshSelected)
+                            .text_size(12)
+                            .width(Length::Fixed(80.0))
+                    )
+                    .push(
+                        Button::new(Text::new("↺ Reset Crosshair").size(12))
+                            .on_press(Message::ResetCrosshair)
+                            .padding(5)
+                    )
+            )
+            
+            // Procedural crosshair (used when no image is selected)
+            .push(self.render_shape_settings())
+            
+            // Extra images drawn on top of the main crosshair
+            .push(self.render_crosshair_layers())
+            .push(self.render_crosshair_presets())
+            
+            // Crosshair adjustment box
+            .push(
+                Container::new(
+                    Column::new()
+                        .spacing(5)
+                        .align_items(Alignment::Center)
+                        .push(Text::new("Position Adjustment").size(14))
+                        .push(
+                            Row::new()
+                                .spacing(10)
+                                .align_items(Alignment::Center)
+                                .push(Space::new(Length::Fixed(40.0), Length::Shrink))
+                                .push(
+                                    Button::new(Text::new("▲").size(16))
+                                        .on_press(Message::CrosshairMoveUp)
+                                        .padding(8)
+                                        .width(Length::Fixed(40.0))
+                                )
                                 .push(Space::new(Length::Fixed(40.0), Length::Shrink))
                         )
                         .push(
@@ -905,130 +3340,778 @@ impl Application for GameOptimizer {
                 .width(Length::Fixed(200.0))
             )
             
+            // Coarse movement and undo for an accidental center
+            .push(
+                Row::new()
+                    .spacing(5)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("10px:").size(12))
+                    .push(
+                        Button::new(Text::new("◀◀").size(12))
+                            .on_press(Message::CrosshairMoveBy(-10, 0))
+                            .padding(5)
+                    )
+                    .push(
+                        Button::new(Text::new("▲▲").size(12))
+                            .on_press(Message::CrosshairMoveBy(0, -10))
+                            .padding(5)
+                    )
+                    .push(
+                        Button::new(Text::new("▼▼").size(12))
+                            .on_press(Message::CrosshairMoveBy(0, 10))
+                            .padding(5)
+                    )
+                    .push(
+                        Button::new(Text::new("▶▶").size(12))
+                            .on_press(Message::CrosshairMoveBy(10, 0))
+                            .padding(5)
+                    )
+                    .push(Space::new(Length::Fixed(10.0), Length::Shrink))
+                    .push(
+                        if self.pre_center_offset.is_some() {
+                            Button::new(Text::new("↺ Restore").size(12))
+                                .on_press(Message::CrosshairRestore)
+                                .padding(5)
+                        } else {
+                            Button::new(Text::new("↺ Restore").size(12)).padding(5)
+                        }
+                    )
+            )
+            
+            .push(
+                Toggler::new(
+                    Some("🖐 Positioning mode (drag the live crosshair)".to_string()),
+                    self.positioning_mode,
+                    Message::PositioningModeToggled
+                )
+                .width(Length::Shrink)
+            )
+            
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Toggler::new(
+                            Some("📌 Force on top (off = smoother in windowed mode, on = survives fullscreen)".to_string()),
+                            self.edit_aggressive_topmost,
+                            Message::AggressiveTopmostToggled
+                        )
+                        .width(Length::Shrink)
+                    )
+                    .push(Text::new("every").size(12))
+                    .push(
+                        PickList::new(
+                            &TopmostInterval::OPTIONS[..],
+                            Some(self.edit_topmost_interval),
+                            Message::TopmostIntervalSelected
+                        )
+                        .text_size(12)
+                        .width(Length::Fixed(90.0))
+                    )
+            )
+            
+            .push(
+                Toggler::new(
+                    Some("🔁 Re-assert on top when the active window changes (e.g. game HUD covers the crosshair)".to_string()),
+                    self.edit_topmost_on_foreground,
+                    Message::TopmostOnForegroundToggled
+                )
+                .width(Length::Shrink)
+            )
+            
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Toggler::new(
+                            Some("🙈 Hide when the game isn't focused (e.g. alt-tabbed to the desktop)".to_string()),
+                            self.edit_hide_when_unfocused,
+                            Message::HideWhenUnfocusedToggled
+                        )
+                        .width(Length::Shrink)
+                    )
+                    .push(
+                        TextInput::new("Game process, e.g. cs2.exe", &self.edit_game_process)
+                            .id(EditorField::GameProcess.id())
+                            .on_input(Message::GameProcessChanged)
+                            .style(styles::validated_input(
+                                !self.edit_hide_when_unfocused || !self.edit_game_process.trim().is_empty()
+                            ))
+                            .width(Length::Fixed(180.0))
+                            .padding(5)
+                    )
+            )
+            
             // Manual offset input (for precise values)
             .push(
                 Row::new()
-                    .spacing(15)
+                    .spacing(15)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("Manual:").size(12))
+                    .push(
+                        Row::new()
+                            .spacing(5)
+                            .align_items(Alignment::Center)
+                            .push(Text::new("X").size(12))
+                            .push(
+                                TextInput::new("0", &self.edit_x_offset)
+                                    .id(EditorField::XOffset.id())
+                                    .on_input(Message::CrosshairOffsetXChanged)
+                                    .on_submit(Message::SaveProfile)
+                                    .style(styles::validated_input(self.edit_x_offset.parse::<i32>().is_ok()))
+                                    .width(Length::Fixed(60.0))
+                                    .padding(5)
+                            )
+                    )
+                    .push(
+                        Row::new()
+                            .spacing(5)
+                            .align_items(Alignment::Center)
+                            .push(Text::new("Y").size(12))
+                            .push(
+                                TextInput::new("0", &self.edit_y_offset)
+                                    .id(EditorField::YOffset.id())
+                                    .on_input(Message::CrosshairOffsetYChanged)
+                                    .on_submit(Message::SaveProfile)
+                                    .style(styles::validated_input(self.edit_y_offset.parse::<i32>().is_ok()))
+                                    .width(Length::Fixed(60.0))
+                                    .padding(5)
+                            )
+                    )
+            )
+            
+            .push(
+                Checkbox::new("Enable crosshair overlay", self.edit_overlay_enabled)
+                    .on_toggle(Message::OverlayEnabledToggled)
+            )
+            
+            .push(Space::new(Length::Fill, Length::Fixed(20.0)))
+            
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(Text::new("💾 Save Profile"))
+                            .on_press(Message::SaveProfile)
+                            .padding(12)
+                    )
+                    .push(
+                        if self.selected_is_active() {
+                            Button::new(Text::new("🔁 Save & Reapply"))
+                                .on_press(Message::SaveAndReapply)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("🔁 Save & Reapply")).padding(12)
+                        }
+                    )
+                    .push(
+                        if unsaved {
+                            Button::new(Text::new("↩ Revert"))
+                                .on_press(Message::RevertProfile)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("↩ Revert")).padding(12)
+                        }
+                    )
+                    .push(
+                        if self.selected_profile_index.is_some() {
+                            Button::new(Text::new("🗑️ Delete"))
+                                .on_press(Message::DeleteProfile)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("🗑️ Delete")).padding(12)
+                        }
+                    )
+                    .push(
+                        if self.selected_profile_index.is_some() {
+                            Button::new(Text::new("📄 Duplicate"))
+                                .on_press(Message::DuplicateProfile)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("📄 Duplicate")).padding(12)
+                        }
+                    )
+                    .push(
+                        if self.selected_profile_index.is_some() && self.profiles.len() > 1 {
+                            Button::new(Text::new("📋 Copy settings →"))
+                                .on_press(Message::OpenCopySettings)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("📋 Copy settings →")).padding(12)
+                        }
+                    )
+                    .push(
+                        Button::new(Text::new("👁 Preview"))
+                            .on_press(Message::PreviewKill)
+                            .padding(12)
+                    )
+                    .push(
+                        if self.selected_profile_index.is_some() {
+                            Button::new(Text::new("⚡ ACTIVATE"))
+                                .on_press(Message::ActivateProfile)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("⚡ ACTIVATE")).padding(12)
+                        }
+                    )
+                    .push(
+                        if self.active_profile_name.is_some() {
+                            Button::new(Text::new("⏹ Deactivate"))
+                                .on_press(Message::DeactivateProfile)
+                                .padding(12)
+                        } else {
+                            Button::new(Text::new("⏹ Deactivate")).padding(12)
+                        }
+                    )
+            )
+            .push(self.render_copy_settings());
+        
+        let right_panel = match self.page {
+            Page::Welcome => Container::new(
+                Scrollable::new(self.render_welcome_page())
+            ),
+            Page::Profiles => Container::new(
+                Scrollable::new(edit_section)
+            ),
+            Page::Logs => Container::new(self.render_logs_page()),
+        }
+        .width(Length::Fill)
+        .height(Length::Fill);
+        
+        let content = Column::new()
+            .push(
+                Row::new()
+                    .push(left_panel)
+                    .push(right_panel)
+                    .height(Length::FillPortion(9))
+            )
+            .push(
+                Container::new(
+                    Row::new()
+                        .spacing(20)
+                        .push(Text::new(&self.status_message).size(14))
+                        .push(
+                            if self.last_kill_report.is_some() {
+                                Button::new(Text::new("📋 Copy report").size(12))
+                                    .on_press(Message::CopyKillReport)
+                                    .padding(3)
+                            } else {
+                                Button::new(Text::new("📋 Copy report").size(12)).padding(3)
+                            }
+                        )
+                        .push(Space::new(Length::Fill, Length::Shrink))
+                        .push(
+                            if let Some(ref name) = self.active_profile_name {
+                                Text::new(format!("🟢 Active: {}", name)).size(14)
+                            } else {
+                                Text::new("No active profile").size(14)
+                            }
+                        )
+                        .push(self.render_tray_status())
+                )
+                .width(Length::Fill)
+                .padding(10)
+                .height(Length::FillPortion(1))
+            );
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+impl GameOptimizer {
+    /// Status bar indicator for the tray icon, with a retry when it couldn't be created
+    fn render_tray_status(&self) -> Element<Message> {
+        if self.tray_manager.is_some() {
+            Text::new("📌 Tray connected").size(14).into()
+        } else {
+            Row::new()
+                .spacing(8)
+                .align_items(Alignment::Center)
+                .push(Text::new("⚠️ Tray disconnected").size(14))
+                .push(
+                    Button::new(Text::new("Reconnect").size(12))
+                        .on_press(Message::ReconnectTray)
+                        .padding(4)
+                )
+                .into()
+        }
+    }
+    
+    fn render_welcome_page(&self) -> Element<Message> {
+        let mut apps = Column::new().spacing(3);
+        for ((name, exe), status) in COMMON_APPS.iter().zip(&self.common_app_status) {
+            // Apps that aren't on this PC would only clutter the first screen
+            if *status == AppStatus::NotFound {
+                continue;
+            }
+            let exe_string = exe.to_string();
+            apps = apps.push(
+                Checkbox::new(format!("{} ({}, {})", name, exe, status), self.welcome_apps.contains(*exe))
+                    .on_toggle(move |checked| Message::WelcomeAppToggled(exe_string.clone(), checked))
+            );
+        }
+        
+        Column::new()
+            .spacing(15)
+            .padding(20)
+            .push(Text::new("👋 Welcome to Gaming Optimizer").size(24))
+            .push(
+                Text::new("Let's set up a starter \"Gaming\" profile. Activating it closes the apps you tick below \
+                           and shows a crosshair; everything can be changed later in the profile editor.")
+                    .size(14)
+            )
+            .push(Text::new("🎯 Apps to close while gaming").size(18))
+            .push(
+                if self.common_app_status.iter().all(|status| *status == AppStatus::NotFound) {
+                    Element::from(Text::new("None of the common apps were found - add processes later from the running list").size(12))
+                } else {
+                    Element::from(apps)
+                }
+            )
+            .push(Text::new("➕ Crosshair").size(18))
+            .push(
+                Checkbox::new("Use the built-in crosshair", self.welcome_crosshair)
+                    .on_toggle(Message::WelcomeCrosshairToggled)
+            )
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(Text::new("✅ Create Profile"))
+                            .on_press(Message::FinishWelcome)
+                            .padding(12)
+                    )
+                    .push(
+                        Button::new(Text::new("Skip"))
+                            .on_press(Message::ShowPage(Page::Profiles))
+                            .padding(12)
+                    )
+            )
+            .into()
+    }
+    
+    fn render_logs_page(&self) -> Element<Message> {
+        let path = logging::current_log_path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        
+        let mut lines = Column::new().spacing(2);
+        for line in &self.log_lines {
+            lines = lines.push(
+                Text::new(line.as_str())
+                    .size(12)
+                    .font(iced::Font::MONOSPACE)
+                    .style(styles::log_line(logging::line_level(line)))
+            );
+        }
+        
+        Column::new()
+            .spacing(10)
+            .padding(20)
+            .push(Text::new("📜 Logs").size(24))
+            .push(Text::new(format!("Last {} lines of {}", LOG_VIEW_LINES, path)).size(12))
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(Text::new("📋 Copy to Clipboard"))
+                            .on_press(Message::CopyLogs)
+                            .padding(8)
+                    )
+                    .push(
+                        Button::new(Text::new("📂 Open Log Folder"))
+                            .on_press(Message::OpenLogFolder)
+                            .padding(8)
+                    )
+            )
+            .push(
+                Scrollable::new(lines)
+                    .id(LOG_SCROLL_ID.clone())
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+            )
+            .into()
+    }
+    
+    fn render_priority_rules(&self) -> Element<Message> {
+        let mut rules = Column::new().spacing(5);
+        
+        for (i, (name, class)) in self.edit_priority_rules.iter().enumerate() {
+            rules = rules.push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(Text::new(format!("{} → {}", name, class)).width(Length::Fill))
+                    .push(
+                        Button::new(Text::new("❌").size(12))
+                            .on_press(Message::RemovePriorityRule(i))
+                            .padding(5)
+                    )
+            );
+        }
+        
+        rules
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        TextInput::new("Process name (e.g. game.exe)", &self.priority_rule_name)
+                            .on_input(Message::PriorityNameChanged)
+                            .on_submit(Message::AddPriorityRule)
+                            .padding(5)
+                            .width(Length::Fill)
+                    )
+                    .push(
+                        PickList::new(&PriorityClass::ALL[..], Some(self.priority_rule_class), Message::PriorityClassSelected)
+                            .width(Length::Fixed(130.0))
+                    )
+                    .push(
+                        Button::new(Text::new("+ Add"))
+                            .on_press(Message::AddPriorityRule)
+                            .padding(5)
+                    )
+            )
+            .into()
+    }
+    
+    fn render_shape_settings(&self) -> Element<Message> {
+        let (pixels, width, height) = self.edit_crosshair_shape().render_rgba();
+        let selected_color = COLOR_PRESETS
+            .iter()
+            .find(|preset| preset.rgb == self.edit_crosshair_color)
+            .copied();
+        
+        Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(
+                PickList::new(&CrosshairKind::ALL[..], Some(self.edit_crosshair_kind), Message::CrosshairKindSelected)
+                    .width(Length::Fixed(90.0))
+            )
+            .push(Text::new(format!("Size {}", self.edit_crosshair_size)).size(12))
+            .push(
+                Slider::new(2..=100, self.edit_crosshair_size, Message::CrosshairSizeChanged)
+                    .on_release(Message::CrosshairShapeCommitted)
+                    .width(Length::Fixed(100.0))
+            )
+            .push(Text::new(format!("Thickness {}", self.edit_crosshair_thickness)).size(12))
+            .push(
+                Slider::new(1..=20, self.edit_crosshair_thickness, Message::CrosshairThicknessChanged)
+                    .on_release(Message::CrosshairShapeCommitted)
+                    .width(Length::Fixed(80.0))
+            )
+            .push(Text::new(format!("Gap {}", self.edit_crosshair_gap)).size(12))
+            .push(
+                Slider::new(0..=20, self.edit_crosshair_gap, Message::CrosshairGapChanged)
+                    .on_release(Message::CrosshairShapeCommitted)
+                    .width(Length::Fixed(80.0))
+            )
+            .push(
+                PickList::new(COLOR_PRESETS, selected_color, Message::CrosshairColorSelected)
+                    .placeholder("Color")
+                    .width(Length::Fixed(90.0))
+            )
+            .push(
+                iced::widget::image(iced::widget::image::Handle::from_pixels(width, height, pixels))
+            )
+            .into()
+    }
+    
+    fn render_crosshair_layers(&self) -> Element<Message> {
+        let mut column = Column::new()
+            .spacing(5)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(Text::new(format!("Extra layers ({})", self.edit_crosshair_layers.len())).size(14))
+                    .push(
+                        Button::new(Text::new("➕ Add Layer").size(12))
+                            .on_press(Message::AddCrosshairLayer)
+                            .padding(5)
+                    )
+            );
+        
+        for (index, layer) in self.edit_crosshair_layers.iter().enumerate() {
+            let file_name = layer.image_path.split('\\').last().unwrap_or(&layer.image_path);
+            column = column.push(
+                Row::new()
+                    .spacing(8)
+                    .align_items(Alignment::Center)
+                    .push(Text::new(file_name.to_string()).size(12).width(Length::Fixed(120.0)))
+                    .push(Text::new(format!("X {}", layer.x_offset)).size(12))
+                    .push(
+                        Slider::new(-500..=500, layer.x_offset, move |x| Message::CrosshairLayerXChanged(index, x))
+                            .on_release(Message::CrosshairShapeCommitted)
+                            .width(Length::Fixed(90.0))
+                    )
+                    .push(Text::new(format!("Y {}", layer.y_offset)).size(12))
+                    .push(
+                        Slider::new(-500..=500, layer.y_offset, move |y| Message::CrosshairLayerYChanged(index, y))
+                            .on_release(Message::CrosshairShapeCommitted)
+                            .width(Length::Fixed(90.0))
+                    )
+                    .push(Text::new(format!("Scale {}%", layer.scale_percent)).size(12))
+                    .push(
+                        Slider::new(10..=400, layer.scale_percent, move |scale| Message::CrosshairLayerScaleChanged(index, scale))
+                            .on_release(Message::CrosshairShapeCommitted)
+                            .width(Length::Fixed(80.0))
+                    )
+                    .push(Text::new(format!("Opacity {}%", layer.opacity_percent)).size(12))
+                    .push(
+                        Slider::new(5..=100, layer.opacity_percent, move |opacity| Message::CrosshairLayerOpacityChanged(index, opacity))
+                            .on_release(Message::CrosshairShapeCommitted)
+                            .width(Length::Fixed(80.0))
+                    )
+                    .push(
+                        Button::new(Text::new("🗑").size(12))
+                            .on_press(Message::RemoveCrosshairLayer(index))
+                            .padding(5)
+                    )
+            );
+        }
+        
+        column.into()
+    }
+    
+    fn render_crosshair_presets(&self) -> Element<Message> {
+        let shortcut = self.edit_cycle_shortcut.trim();
+        let shortcut_valid = shortcut.is_empty() || Hotkey::parse(shortcut).is_ok();
+        
+        let mut column = Column::new()
+            .spacing(5)
+            .push(
+                Row::new()
+                    .spacing(10)
                     .align_items(Alignment::Center)
-                    .push(Text::new("Manual:").size(12))
+                    .push(Text::new(format!("Crosshair presets ({})", self.edit_crosshair_presets.len())).size(14))
                     .push(
-                        Row::new()
-                            .spacing(5)
-                            .align_items(Alignment::Center)
-                            .push(Text::new("X").size(12))
-                            .push(
-                                TextInput::new("0", &self.edit_x_offset)
-                                    .on_input(Message::CrosshairOffsetXChanged)
-                                    .width(Length::Fixed(60.0))
-                                    .padding(5)
-                            )
+                        Button::new(Text::new("➕ Save current as preset").size(12))
+                            .on_press(Message::AddCrosshairPreset)
+                            .padding(5)
                     )
+                    .push(Space::new(Length::Fill, Length::Shrink))
+                    .push(Text::new("Cycle shortcut").size(12))
                     .push(
-                        Row::new()
-                            .spacing(5)
-                            .align_items(Alignment::Center)
-                            .push(Text::new("Y").size(12))
-                            .push(
-                                TextInput::new("0", &self.edit_y_offset)
-                                    .on_input(Message::CrosshairOffsetYChanged)
-                                    .width(Length::Fixed(60.0))
-                                    .padding(5)
-                            )
+                        TextInput::new("e.g. Ctrl+Alt+C", &self.edit_cycle_shortcut)
+                            .on_input(Message::CycleShortcutChanged)
+                            .style(styles::validated_input(shortcut_valid))
+                            .width(Length::Fixed(120.0))
+                            .padding(5)
                     )
-            )
-            
-            .push(
-                Checkbox::new("Enable crosshair overlay", self.edit_overlay_enabled)
-                    .on_toggle(Message::OverlayEnabledToggled)
-            )
+            );
+        
+        for (index, preset) in self.edit_crosshair_presets.iter().enumerate() {
+            let look = match preset.image_path {
+                Some(ref path) => path.rsplit('\\').next().unwrap_or(path).to_string(),
+                None => format!("{} {}px", preset.kind, preset.size),
+            };
+            column = column.push(
+                Row::new()
+                    .spacing(8)
+                    .align_items(Alignment::Center)
+                    .push(Text::new(preset.name.clone()).size(12).width(Length::Fixed(80.0)))
+                    .push(Text::new(format!("{} @ ({}, {})", look, preset.x_offset, preset.y_offset)).size(12).width(Length::Fill))
+                    .push(
+                        Button::new(Text::new("Use").size(12))
+                            .on_press(Message::UseCrosshairPreset(index))
+                            .padding(5)
+                    )
+                    .push(
+                        Button::new(Text::new("🗑").size(12))
+                            .on_press(Message::RemoveCrosshairPreset(index))
+                            .padding(5)
+                    )
+            );
+        }
+        
+        column.into()
+    }
+    
+    fn render_common_apps(&self) -> Element<Message> {
+        let filter = self.process_filter.trim();
+        let mut grid = Column::new().spacing(3);
+        
+        for ((name, exe), status) in COMMON_APPS.iter().zip(&self.common_app_status) {
+            if fuzzy_score(filter, exe).max(fuzzy_score(filter, name)).is_none() {
+                continue;
+            }
             
-            .push(Space::new(Length::Fill, Length::Fixed(20.0)))
+            let is_selected = self.process_selection.get(*exe).copied().unwrap_or(false);
+            let exe_string = exe.to_string();
+            let marker = match status {
+                AppStatus::Running => "🟢",
+                AppStatus::Installed => "⚪",
+                AppStatus::NotFound => "➖",
+            };
             
+            grid = grid.push(
+                Checkbox::new(format!("{} {} ({}, {})", marker, name, exe, status), is_selected)
+                    .on_toggle(move |checked| Message::ProcessToggled(exe_string.clone(), checked))
+                    .width(Length::Fill)
+            );
+        }
+        
+        Container::new(
+            Scrollable::new(grid).height(Length::Fixed(150.0))
+        )
+        .width(Length::Fill)
+        .into()
+    }
+    
+    fn render_copy_settings(&self) -> Element<Message> {
+        let (Some(ref form), Some(source_index)) = (&self.copy_settings, self.selected_profile_index) else {
+            return Space::new(Length::Shrink, Length::Shrink).into();
+        };
+        let fields = form.fields;
+        
+        let mut panel = Column::new()
+            .spacing(5)
             .push(
                 Row::new()
                     .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(Text::new(format!("Copy saved settings of '{}' to other profiles", self.profiles[source_index].name)).size(14))
+                    .push(Space::new(Length::Fill, Length::Shrink))
                     .push(
-                        Button::new(Text::new("💾 Save Profile"))
-                            .on_press(Message::SaveProfile)
-                            .padding(12)
+                        Button::new(Text::new("✕").size(12))
+                            .on_press(Message::CloseCopySettings)
+                            .padding(3)
                     )
+            )
+            .push(
+                Row::new()
+                    .spacing(15)
                     .push(
-                        if self.selected_profile_index.is_some() {
-                            Button::new(Text::new("🗑️ Delete"))
-                                .on_press(Message::DeleteProfile)
-                                .padding(12)
-                        } else {
-                            Button::new(Text::new("🗑️ Delete")).padding(12)
-                        }
+                        Checkbox::new("Crosshair", fields.crosshair)
+                            .on_toggle(move |on| Message::CopyFieldsChanged(CopyFields { crosshair: on, ..fields }))
+                            .text_size(12)
                     )
                     .push(
-                        if self.selected_profile_index.is_some() {
-                            Button::new(Text::new("⚡ ACTIVATE"))
-                                .on_press(Message::ActivateProfile)
-                                .padding(12)
-                        } else {
-                            Button::new(Text::new("⚡ ACTIVATE")).padding(12)
-                        }
+                        Checkbox::new("Processes to kill", fields.processes)
+                            .on_toggle(move |on| Message::CopyFieldsChanged(CopyFields { processes: on, ..fields }))
+                            .text_size(12)
                     )
+                    .push(
+                        Checkbox::new("Fan", fields.fan)
+                            .on_toggle(move |on| Message::CopyFieldsChanged(CopyFields { fan: on, ..fields }))
+                            .text_size(12)
+                    )
+            )
+            .push(Text::new("Into:").size(12));
+        
+        for (index, profile) in self.profiles.iter().enumerate().filter(|(i, _)| *i != source_index) {
+            panel = panel.push(
+                Checkbox::new(profile.name.clone(), form.targets.contains(&index))
+                    .on_toggle(move |checked| Message::CopyTargetToggled(index, checked))
+                    .text_size(12)
             );
+        }
         
-        let right_panel = Container::new(
-            Scrollable::new(edit_section)
-        )
-        .width(Length::Fill)
-        .height(Length::Fill);
+        let anything = fields.crosshair || fields.processes || fields.fan;
+        let mut targets: Vec<usize> = form.targets.iter().copied().collect();
+        targets.sort_unstable();
+        panel = panel.push(
+            if anything && !targets.is_empty() {
+                Button::new(Text::new(format!("📋 Copy to {} profile(s)", targets.len())).size(12))
+                    .on_press(Message::CopySettingsTo { targets, fields })
+                    .padding(6)
+            } else {
+                Button::new(Text::new("📋 Copy").size(12)).padding(6)
+            }
+        );
         
-        let content = Column::new()
+        Container::new(panel)
+            .padding(10)
+            .width(Length::Fill)
+            .into()
+    }
+    
+    fn render_quick_scan(&self) -> Element<Message> {
+        let Some(ref top) = self.quick_scan else {
+            return Space::new(Length::Shrink, Length::Shrink).into();
+        };
+        
+        let mut list = Column::new()
+            .spacing(3)
             .push(
                 Row::new()
-                    .push(left_panel)
-                    .push(right_panel)
-                    .height(Length::FillPortion(9))
-            )
-            .push(
-                Container::new(
-                    Row::new()
-                        .spacing(20)
-                        .push(Text::new(&self.status_message).size(14))
-                        .push(Space::new(Length::Fill, Length::Shrink))
-                        .push(
-                            if let Some(ref name) = self.active_profile_name {
-                                Text::new(format!("🟢 Active: {} | 📌 Tray", name)).size(14)
-                            } else {
-                                Text::new("No active profile | 📌 Tray").size(14)
-                            }
-                        )
-                )
-                .width(Length::Fill)
-                .padding(10)
-                .height(Length::FillPortion(1))
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("Heaviest apps right now (protected processes are left out):").size(12))
+                    .push(Space::new(Length::Fill, Length::Shrink))
+                    .push(
+                        Button::new(Text::new("✕").size(12))
+                            .on_press(Message::DismissQuickScan)
+                            .padding(3)
+                    )
             );
-
-        Container::new(content)
+        
+        if top.is_empty() {
+            list = list.push(Text::new("Nothing worth closing was found").size(12));
+        }
+        
+        for (rank, usage) in top.iter().enumerate() {
+            let is_selected = self.process_selection.get(&usage.name).copied().unwrap_or(false);
+            let instances = if usage.instances > 1 { format!(" ×{}", usage.instances) } else { String::new() };
+            
+            list = list.push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(
+                        Text::new(format!(
+                            "{}. {}{} - CPU: {:.1}% | {} MB",
+                            rank + 1, usage.name, instances, usage.cpu_percent, usage.memory_kb / 1024
+                        ))
+                        .size(12)
+                        .width(Length::Fill)
+                    )
+                    .push(
+                        if is_selected {
+                            Button::new(Text::new("✓ In profile").size(12))
+                                .on_press(Message::ProcessToggled(usage.name.clone(), false))
+                                .padding(3)
+                        } else {
+                            Button::new(Text::new("➕ Add to profile").size(12))
+                                .on_press(Message::ProcessToggled(usage.name.clone(), true))
+                                .padding(3)
+                        }
+                    )
+            );
+        }
+        
+        Container::new(list)
+            .padding(8)
             .width(Length::Fill)
-            .height(Length::Fill)
             .into()
     }
-}
-
-impl GameOptimizer {
-    fn render_process_selector(&self) -> Element<Message> {
-        let filter_lower = self.process_filter.to_lowercase();
+    
+    /// Process picker rows matching the filter: running processes, plus common apps that are
+    /// selected but not running. Best matches first.
+    fn process_rows(&self) -> Vec<ProcessRow<'_>> {
+        let filter = self.process_filter.trim();
         
         let mut seen: HashSet<String> = HashSet::new();
-        let mut processes_to_show: Vec<(&str, &str, Option<f32>, Option<u64>)> = Vec::new();
+        let mut rows: Vec<ProcessRow> = Vec::new();
         
         for proc in &self.running_processes {
             let name_lower = proc.name.to_lowercase();
             if !seen.contains(&name_lower) {
-                if filter_lower.is_empty() || name_lower.contains(&filter_lower) {
+                if let Some(score) = fuzzy_score(filter, &proc.name) {
                     seen.insert(name_lower);
-                    processes_to_show.push((
-                        &proc.name,
-                        &proc.name,
-                        Some(proc.cpu_percent),
-                        Some(proc.memory_kb)
-                    ));
+                    rows.push(ProcessRow {
+                        display_name: &proc.name,
+                        exe_name: &proc.name,
+                        usage: Some((proc.cpu_percent, proc.memory_kb)),
+                        score,
+                    });
                 }
             }
         }
@@ -1037,28 +4120,37 @@ impl GameOptimizer {
             let exe_lower = exe.to_lowercase();
             if !seen.contains(&exe_lower) {
                 if self.process_selection.get(*exe).copied().unwrap_or(false) {
-                    if filter_lower.is_empty() || exe_lower.contains(&filter_lower) || name.to_lowercase().contains(&filter_lower) {
+                    let score = fuzzy_score(filter, exe).max(fuzzy_score(filter, name));
+                    if let Some(score) = score {
                         seen.insert(exe_lower);
-                        processes_to_show.push((name, exe, None, None));
+                        rows.push(ProcessRow { display_name: name, exe_name: exe, usage: None, score });
                     }
                 }
             }
         }
         
-        processes_to_show.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        // Best matches first while filtering so they survive the list cap
+        rows.sort_by(|a, b| {
+            b.score.cmp(&a.score).then_with(|| a.display_name.to_lowercase().cmp(&b.display_name.to_lowercase()))
+        });
+        rows
+    }
+    
+    fn render_process_selector(&self) -> Element<Message> {
+        let processes_to_show = self.process_rows();
         
         let mut grid = Column::new().spacing(3);
         
         if processes_to_show.is_empty() {
             grid = grid.push(Text::new("No processes found matching filter").size(12));
         } else {
-            for (display_name, exe_name, cpu, mem) in processes_to_show.iter().take(50) {
-                let is_selected = self.process_selection.get(*exe_name).copied().unwrap_or(false);
-                let exe_string = exe_name.to_string();
+            for row in processes_to_show.iter().take(PROCESS_LIST_LIMIT) {
+                let is_selected = self.process_selection.get(row.exe_name).copied().unwrap_or(false);
+                let exe_string = row.exe_name.to_string();
                 
-                let info = match (cpu, mem) {
-                    (Some(c), Some(m)) => format!("{} - CPU: {:.1}% | {} MB", display_name, c, m / 1024),
-                    _ => format!("{} (not running)", display_name),
+                let info = match row.usage {
+                    Some((cpu, mem)) => format!("{} - CPU: {:.1}% | {} MB", row.display_name, cpu, mem / 1024),
+                    None => format!("{} (not running)", row.display_name),
                 };
                 
                 grid = grid.push(
@@ -1068,29 +4160,204 @@ impl GameOptimizer {
                 );
             }
             
-            if processes_to_show.len() > 50 {
+            if processes_to_show.len() > PROCESS_LIST_LIMIT {
                 grid = grid.push(
-                    Text::new(format!("... and {} more (use filter)", processes_to_show.len() - 50)).size(12)
+                    Text::new(format!("... and {} more (use filter)", processes_to_show.len() - PROCESS_LIST_LIMIT)).size(12)
                 );
             }
         }
         
-        Container::new(
-            Scrollable::new(grid).height(Length::Fixed(200.0))
-        )
-        .width(Length::Fill)
-        .into()
+        Column::new()
+            .spacing(5)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(
+                        Button::new(Text::new("☑ Select all visible").size(12))
+                            .on_press(Message::SelectVisibleProcesses { skip_protected: false })
+                            .padding(5)
+                    )
+                    .push(
+                        Button::new(Text::new("☑ Select all non-system").size(12))
+                            .on_press(Message::SelectVisibleProcesses { skip_protected: true })
+                            .padding(5)
+                    )
+            )
+            .push(
+                Container::new(
+                    Scrollable::new(grid).height(Length::Fixed(200.0))
+                )
+                .width(Length::Fill)
+            )
+            .into()
+    }
+}
+
+/// One entry in the process picker
+struct ProcessRow<'a> {
+    display_name: &'a str,
+    exe_name: &'a str,
+    /// CPU % and memory in KB; None for a common app that isn't running
+    usage: Option<(f32, u64)>,
+    /// Fuzzy match score against the filter
+    score: i32,
+}
+
+/// Sidebar ordering of the profile list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileSort {
+    Saved,
+    RecentlyUsed,
+    MostUsed,
+}
+
+impl ProfileSort {
+    const ALL: [ProfileSort; 3] = [ProfileSort::Saved, ProfileSort::RecentlyUsed, ProfileSort::MostUsed];
+}
+
+impl std::fmt::Display for ProfileSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ProfileSort::Saved => "Sort: Saved order",
+            ProfileSort::RecentlyUsed => "Sort: Recently used",
+            ProfileSort::MostUsed => "Sort: Most used",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Map global keyboard shortcuts to messages
+/// Ctrl+S save, Ctrl+N new, Ctrl+D duplicate, Delete delete, Tab/Shift+Tab next/previous field
+fn shortcut_message(key: iced::keyboard::Key, modifiers: iced::keyboard::Modifiers) -> Option<Message> {
+    use iced::keyboard::{key::Named, Key};
+    
+    match key.as_ref() {
+        Key::Character("s") if modifiers.command() => Some(Message::SaveProfile),
+        Key::Character("n") if modifiers.command() => Some(Message::NewProfile),
+        Key::Character("d") if modifiers.command() => Some(Message::DuplicateProfile),
+        Key::Named(Named::Delete) => Some(Message::DeleteProfile),
+        Key::Named(Named::Tab) => Some(Message::TabPressed { backwards: modifiers.shift() }),
+        _ => None,
+    }
+}
+
+/// Names a bulk select adds to the kill list; `skip_protected` leaves out critical Windows processes
+fn bulk_selection<'a>(exe_names: impl Iterator<Item = &'a str>, skip_protected: bool) -> Vec<String> {
+    exe_names
+        .filter(|name| !skip_protected || protection_reason(name).is_none())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether two versions of a profile have the same settings; the kill list is compared as a
+/// set because the editor keeps it in a HashMap
+fn same_settings(a: &Profile, b: &Profile) -> bool {
+    let sorted = |profile: &Profile| {
+        let mut processes = profile.processes_to_kill.clone();
+        processes.sort_by_key(|name| name.to_lowercase());
+        processes
+    };
+    sorted(a) == sorted(b)
+        && Profile { processes_to_kill: Vec::new(), ..a.clone() } == Profile { processes_to_kill: Vec::new(), ..b.clone() }
+}
+
+/// Apply priority rules, returning a status line for the ones that matched a running process
+/// Each changed process is recorded in `changes` with its original class so it can be restored
+fn apply_priority_rules(rules: &[(String, PriorityClass)], changes: &mut Vec<(u32, PriorityClass)>) -> Option<String> {
+    let mut adjusted = Vec::new();
+    for (name, class) in rules {
+        let changed = set_priority(name, *class);
+        if changed.is_empty() {
+            continue;
+        }
+        adjusted.push(format!("{} → {}", name, class));
+        // A process hit again (reapply) keeps the class it had before the first change
+        for (pid, original) in changed {
+            if !changes.iter().any(|(p, _)| *p == pid) {
+                changes.push((pid, original));
+            }
+        }
+    }
+    (!adjusted.is_empty()).then(|| format!("Priority: {}", adjusted.join(", ")))
+}
+
+/// Show a native Yes/No warning dialog, returns true if the user picked Yes
+fn confirm_dialog(title: &str, description: &str) -> bool {
+    rfd::MessageDialog::new()
+        .set_level(rfd::MessageLevel::Warning)
+        .set_title(title)
+        .set_description(description)
+        .set_buttons(rfd::MessageButtons::YesNo)
+        .show() == rfd::MessageDialogResult::Yes
+}
+
+/// Strip everything but digits (and a leading '-' when allowed) from numeric input
+/// Leaves partial input like "" or "-" in place so the user can keep typing
+fn sanitize_int_input(s: &str, allow_negative: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    
+    for (i, c) in s.trim().chars().enumerate() {
+        if c.is_ascii_digit() || (allow_negative && c == '-' && i == 0) {
+            out.push(c);
+        }
+    }
+    
+    out
+}
+
+/// Fuzzy subsequence match for the process filter ("chrm" matches "chrome.exe")
+/// Returns None when the needle isn't a subsequence; higher scores are better matches.
+/// Consecutive characters, word starts and early matches score higher, gaps cost a little.
+fn fuzzy_score(needle: &str, haystack: &str) -> Option<i32> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    
+    let hay: Vec<char> = haystack.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last_match: Option<usize> = None;
+    
+    for c in needle.chars().flat_map(char::to_lowercase) {
+        let offset = hay[pos..].iter().position(|&h| h == c)?;
+        let index = pos + offset;
+        
+        score += 10;
+        if last_match.is_some_and(|last| last + 1 == index) {
+            score += 15;
+        }
+        if index == 0 || matches!(hay[index - 1], ' ' | '.' | '_' | '-') {
+            score += 10;
+        }
+        if last_match.is_none() {
+            score -= index.min(10) as i32;
+        } else {
+            score -= offset.min(5) as i32;
+        }
+        
+        last_match = Some(index);
+        pos = index + 1;
     }
+    
+    // Prefer shorter names when everything else is equal
+    Some(score - hay.len().min(20) as i32 / 4)
 }
 
-pub fn run() -> iced::Result {
+/// `start_minimized` leaves the window hidden; the tray's Settings item or a double-click shows it
+pub fn run(start_minimized: bool) -> iced::Result {
     println!("[GUI] Starting GUI with integrated tray...");
+    if start_minimized {
+        println!("[GUI] Starting minimized to tray");
+    }
     
     // Tray is created inside Application::new() on main thread
     let result = GameOptimizer::run(Settings {
         window: iced::window::Settings {
             size: iced::Size::new(1000.0, 750.0),
             min_size: Some(iced::Size::new(900.0, 650.0)),
+            // WindowCloseRequested decides between hiding to tray and exiting
+            exit_on_close_request: false,
+            visible: !start_minimized,
             ..Default::default()
         },
         ..Default::default()
@@ -1099,3 +4366,77 @@ pub fn run() -> iced::Result {
     println!("[GUI] Iced returned: {:?}", result);
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_int_input() {
+        assert_eq!(sanitize_int_input("123", false), "123");
+        assert_eq!(sanitize_int_input("12a3", false), "123");
+        assert_eq!(sanitize_int_input("abc", false), "");
+        assert_eq!(sanitize_int_input("-42", true), "-42");
+        assert_eq!(sanitize_int_input("-42", false), "42");
+        assert_eq!(sanitize_int_input("-", true), "-");
+        assert_eq!(sanitize_int_input("4-2", true), "42");
+    }
+
+    #[test]
+    fn test_page_config_name() {
+        assert_eq!(Page::from_config_name(Page::Logs.config_name()), Page::Logs);
+        assert_eq!(Page::from_config_name(Page::Welcome.config_name()), Page::Profiles);
+        assert_eq!(Page::from_config_name(""), Page::Profiles);
+    }
+
+    #[test]
+    fn test_editor_tab_order() {
+        assert_eq!(EditorField::after(None, false), EditorField::Name);
+        assert_eq!(EditorField::after(None, true), EditorField::YOffset);
+        assert_eq!(EditorField::after(Some(EditorField::Name), false), EditorField::Tags);
+        assert_eq!(EditorField::after(Some(EditorField::YOffset), false), EditorField::Name);
+        assert_eq!(EditorField::after(Some(EditorField::Name), true), EditorField::YOffset);
+        
+        let id = WidgetId::from(EditorField::ProcessFilter.id());
+        assert_eq!(EditorField::from_id(&id), Some(EditorField::ProcessFilter));
+        assert_eq!(EditorField::from_id(&WidgetId::unique()), None);
+    }
+
+    #[test]
+    fn test_same_settings() {
+        let mut saved = create_profile("CS2".to_string());
+        saved.processes_to_kill = vec!["chrome.exe".to_string(), "Discord.exe".to_string()];
+        
+        let mut form = saved.clone();
+        form.processes_to_kill.reverse();
+        assert!(same_settings(&form, &saved));
+        
+        form.crosshair_x_offset += 1;
+        assert!(!same_settings(&form, &saved));
+        
+        let mut form = saved.clone();
+        form.processes_to_kill.pop();
+        assert!(!same_settings(&form, &saved));
+    }
+
+    #[test]
+    fn test_bulk_selection() {
+        let names = ["chrome.exe", "svchost.exe", "Discord.exe"];
+        assert_eq!(bulk_selection(names.into_iter(), true), vec!["chrome.exe", "Discord.exe"]);
+        assert_eq!(bulk_selection(names.into_iter(), false).len(), 3);
+    }
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "chrome.exe"), Some(0));
+        assert!(fuzzy_score("chrm", "chrome.exe").is_some());
+        assert!(fuzzy_score("CHRM", "chrome.exe").is_some());
+        assert!(fuzzy_score("mrhc", "chrome.exe").is_none());
+        
+        // Prefix beats scattered, consecutive beats gapped
+        let prefix = fuzzy_score("dis", "discord.exe").unwrap();
+        let scattered = fuzzy_score("dis", "nvidia_share.exe").unwrap();
+        assert!(prefix > scattered);
+        assert!(fuzzy_score("steam", "steam.exe") > fuzzy_score("steam", "steamwebhelper.exe"));
+    }
+}
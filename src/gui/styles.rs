@@ -1,5 +1,132 @@
 /// ICED theme and styling
+use iced::widget::{button, container};
+use iced::widget::text_input::{self, Appearance};
+use iced::{Border, Color, Theme};
 
 pub fn theme() -> iced::Theme {
     iced::Theme::Dark
 }
+
+/// Text input with a red border for empty or invalid values
+pub struct InvalidInput;
+
+impl text_input::StyleSheet for InvalidInput {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> Appearance {
+        with_error_border(style.active(&iced::theme::TextInput::Default))
+    }
+
+    fn focused(&self, style: &Self::Style) -> Appearance {
+        with_error_border(style.focused(&iced::theme::TextInput::Default))
+    }
+
+    fn placeholder_color(&self, style: &Self::Style) -> Color {
+        style.placeholder_color(&iced::theme::TextInput::Default)
+    }
+
+    fn value_color(&self, style: &Self::Style) -> Color {
+        style.value_color(&iced::theme::TextInput::Default)
+    }
+
+    fn disabled_color(&self, style: &Self::Style) -> Color {
+        style.disabled_color(&iced::theme::TextInput::Default)
+    }
+
+    fn selection_color(&self, style: &Self::Style) -> Color {
+        style.selection_color(&iced::theme::TextInput::Default)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> Appearance {
+        style.disabled(&iced::theme::TextInput::Default)
+    }
+}
+
+fn with_error_border(mut appearance: Appearance) -> Appearance {
+    appearance.border.color = Color::from_rgb(0.9, 0.3, 0.3);
+    appearance
+}
+
+/// Text color for a line in the log viewer
+pub fn log_line(level: crate::logging::LogLevel) -> iced::theme::Text {
+    use crate::logging::LogLevel;
+    match level {
+        LogLevel::Error => iced::theme::Text::Color(Color::from_rgb(0.95, 0.4, 0.4)),
+        LogLevel::Warn => iced::theme::Text::Color(Color::from_rgb(0.95, 0.75, 0.3)),
+        LogLevel::Info => iced::theme::Text::Default,
+    }
+}
+
+/// Muted amber for the editor's "unsaved changes" note
+pub fn unsaved_hint() -> iced::theme::Text {
+    iced::theme::Text::Color(Color::from_rgb(0.95, 0.75, 0.3))
+}
+
+/// Style for a text input that validates as you type, red-bordered while the value is invalid
+pub fn validated_input(valid: bool) -> iced::theme::TextInput {
+    if valid {
+        iced::theme::TextInput::Default
+    } else {
+        iced::theme::TextInput::Custom(Box::new(InvalidInput))
+    }
+}
+
+/// Rounded pill behind a profile tag in the sidebar
+pub struct TagChip;
+
+impl container::StyleSheet for TagChip {
+    type Style = Theme;
+
+    fn appearance(&self, style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(style.extended_palette().background.strong.color.into()),
+            border: Border {
+                radius: 8.0.into(),
+                ..Border::default()
+            },
+            ..container::Appearance::default()
+        }
+    }
+}
+
+pub fn tag_chip() -> iced::theme::Container {
+    iced::theme::Container::Custom(Box::new(TagChip))
+}
+
+/// Sidebar button of a just-activated profile; `strength` fades from 1.0 to 0.0
+pub struct ActivationFlash(pub f32);
+
+impl button::StyleSheet for ActivationFlash {
+    type Style = Theme;
+
+    fn active(&self, style: &Self::Style) -> button::Appearance {
+        let base = style.active(&iced::theme::Button::Primary);
+        let flash = style.extended_palette().success.base.color;
+        let background = match base.background {
+            Some(iced::Background::Color(color)) => mix(color, flash, self.0),
+            _ => Color { a: self.0, ..flash },
+        };
+        button::Appearance {
+            background: Some(background.into()),
+            ..base
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> button::Appearance {
+        self.active(style)
+    }
+}
+
+pub fn activation_flash(strength: f32) -> iced::theme::Button {
+    iced::theme::Button::Custom(Box::new(ActivationFlash(strength)))
+}
+
+/// Blend `from` towards `to` by `amount` (0.0 = from, 1.0 = to)
+fn mix(from: Color, to: Color, amount: f32) -> Color {
+    Color {
+        r: from.r + (to.r - from.r) * amount,
+        g: from.g + (to.g - from.g) * amount,
+        b: from.b + (to.b - from.b) * amount,
+        a: from.a + (to.a - from.a) * amount,
+    }
+}
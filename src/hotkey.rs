@@ -0,0 +1,200 @@
+//! System-wide hotkeys, written like "Ctrl+Alt+C"
+//!
+//! Registered with RegisterHotKey on a hidden message-only window owned by the GUI thread.
+//! The GUI's message loop dispatches WM_HOTKEY to that window's wndproc even while a game
+//! has focus; the wndproc queues the id and the GUI picks it up with `take_pressed`.
+
+use anyhow::{anyhow, Result};
+use std::sync::Mutex;
+
+/// Hotkey ids pressed since the last `take_pressed`
+static PRESSED: Mutex<Vec<i32>> = Mutex::new(Vec::new());
+
+const MOD_ALT: u32 = 0x0001;
+const MOD_CONTROL: u32 = 0x0002;
+const MOD_SHIFT: u32 = 0x0004;
+const MOD_WIN: u32 = 0x0008;
+
+/// A parsed shortcut: RegisterHotKey modifier bits plus a virtual-key code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hotkey {
+    pub modifiers: u32,
+    pub vk: u32,
+}
+
+impl Hotkey {
+    /// Parse "Ctrl+Shift+X", "Alt+F5", "F8"... (case-insensitive)
+    /// Letters and digits need a modifier so the hotkey doesn't swallow normal typing
+    pub fn parse(text: &str) -> Result<Hotkey> {
+        let mut modifiers = 0;
+        let mut vk = None;
+
+        for part in text.split('+').map(str::trim) {
+            let upper = part.to_ascii_uppercase();
+            match upper.as_str() {
+                "CTRL" | "CONTROL" => modifiers |= MOD_CONTROL,
+                "ALT" => modifiers |= MOD_ALT,
+                "SHIFT" => modifiers |= MOD_SHIFT,
+                "WIN" => modifiers |= MOD_WIN,
+                "" => return Err(anyhow!("Empty key in shortcut '{}'", text)),
+                _ if vk.is_some() => return Err(anyhow!("Shortcut '{}' has more than one key", text)),
+                _ => vk = Some(key_code(&upper).ok_or_else(|| anyhow!("Unknown key '{}'", part))?),
+            }
+        }
+
+        let vk = vk.ok_or_else(|| anyhow!("Shortcut '{}' has no key", text))?;
+        let is_function_key = (0x70..=0x87).contains(&vk);
+        if modifiers == 0 && !is_function_key {
+            return Err(anyhow!("Add Ctrl, Alt, Shift or Win to '{}'", text));
+        }
+        Ok(Hotkey { modifiers, vk })
+    }
+}
+
+impl std::fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (bit, name) in [(MOD_CONTROL, "Ctrl"), (MOD_ALT, "Alt"), (MOD_SHIFT, "Shift"), (MOD_WIN, "Win")] {
+            if self.modifiers & bit != 0 {
+                write!(f, "{}+", name)?;
+            }
+        }
+        match self.vk {
+            0x70..=0x87 => write!(f, "F{}", self.vk - 0x6F),
+            _ => write!(f, "{}", char::from_u32(self.vk).unwrap_or('?')),
+        }
+    }
+}
+
+/// Virtual-key code for A-Z, 0-9 and F1-F24
+fn key_code(key: &str) -> Option<u32> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        // VK codes for letters and digits are their ASCII values
+        return c.is_ascii_alphanumeric().then_some(c as u32);
+    }
+    let n: u32 = key.strip_prefix('F')?.parse().ok()?;
+    (1..=24).contains(&n).then_some(0x6F + n)
+}
+
+/// Hotkey ids pressed since the last call, oldest first
+pub fn take_pressed() -> Vec<i32> {
+    PRESSED.lock().map(|mut pressed| std::mem::take(&mut *pressed)).unwrap_or_default()
+}
+
+/// Register a hotkey on the calling thread's hotkey window; presses show up in `take_pressed`
+/// Must be called from the thread that runs the message loop (the GUI thread)
+#[cfg(windows)]
+pub fn register(id: i32, hotkey: Hotkey) -> Result<()> {
+    use windows::Win32::UI::Input::KeyboardAndMouse::{RegisterHotKey, HOT_KEY_MODIFIERS, MOD_NOREPEAT};
+
+    let hwnd = hotkey_window()?;
+    let modifiers = HOT_KEY_MODIFIERS(hotkey.modifiers) | MOD_NOREPEAT;
+    unsafe { RegisterHotKey(hwnd, id, modifiers, hotkey.vk) }
+        .map_err(|e| anyhow!("{} is already in use by another program ({})", hotkey, e))
+}
+
+/// Message-only window that receives WM_HOTKEY, created on first use
+#[cfg(windows)]
+fn hotkey_window() -> Result<windows::Win32::Foundation::HWND> {
+    use std::sync::atomic::{AtomicIsize, Ordering};
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::HWND;
+    use windows::Win32::System::LibraryLoader::GetModuleHandleW;
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, RegisterClassW, HMENU, HWND_MESSAGE, WINDOW_EX_STYLE, WINDOW_STYLE, WNDCLASSW,
+    };
+
+    static WINDOW: AtomicIsize = AtomicIsize::new(0);
+    let existing = WINDOW.load(Ordering::Relaxed);
+    if existing != 0 {
+        return Ok(HWND(existing));
+    }
+
+    unsafe {
+        let hinstance = GetModuleHandleW(None)?;
+        let class_name = "EdgeOptimizerHotkeyWindow".encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+        let wc = WNDCLASSW {
+            lpfnWndProc: Some(hotkey_wndproc),
+            hInstance: hinstance.into(),
+            lpszClassName: PCWSTR(class_name.as_ptr()),
+            ..Default::default()
+        };
+        RegisterClassW(&wc);
+
+        let hwnd = CreateWindowExW(
+            WINDOW_EX_STYLE::default(),
+            PCWSTR(class_name.as_ptr()),
+            PCWSTR::null(),
+            WINDOW_STYLE::default(),
+            0,
+            0,
+            0,
+            0,
+            HWND_MESSAGE,
+            HMENU::default(),
+            hinstance,
+            None,
+        );
+        if hwnd.0 == 0 {
+            return Err(anyhow!("Failed to create the hotkey window"));
+        }
+        WINDOW.store(hwnd.0, Ordering::Relaxed);
+        Ok(hwnd)
+    }
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn hotkey_wndproc(
+    hwnd: windows::Win32::Foundation::HWND,
+    msg: u32,
+    wparam: windows::Win32::Foundation::WPARAM,
+    lparam: windows::Win32::Foundation::LPARAM,
+) -> windows::Win32::Foundation::LRESULT {
+    use windows::Win32::UI::WindowsAndMessaging::{DefWindowProcW, WM_HOTKEY};
+
+    if msg == WM_HOTKEY {
+        if let Ok(mut pressed) = PRESSED.lock() {
+            pressed.push(wparam.0 as i32);
+        }
+        return windows::Win32::Foundation::LRESULT(0);
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
+#[cfg(not(windows))]
+pub fn register(_id: i32, _hotkey: Hotkey) -> Result<()> {
+    Ok(())
+}
+
+/// Release a hotkey registered by `register` on this thread
+#[cfg(windows)]
+pub fn unregister(id: i32) {
+    use windows::Win32::UI::Input::KeyboardAndMouse::UnregisterHotKey;
+
+    if let Ok(hwnd) = hotkey_window() {
+        let _ = unsafe { UnregisterHotKey(hwnd, id) };
+    }
+}
+
+#[cfg(not(windows))]
+pub fn unregister(_id: i32) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hotkey() {
+        let hotkey = Hotkey::parse("ctrl + alt + c").unwrap();
+        assert_eq!(hotkey, Hotkey { modifiers: MOD_CONTROL | MOD_ALT, vk: 'C' as u32 });
+        assert_eq!(hotkey.to_string(), "Ctrl+Alt+C");
+        assert_eq!(Hotkey::parse("F8").unwrap().to_string(), "F8");
+        assert_eq!(Hotkey::parse("Shift+F12").unwrap().vk, 0x7B);
+
+        assert!(Hotkey::parse("C").is_err());
+        assert!(Hotkey::parse("Ctrl+").is_err());
+        assert!(Hotkey::parse("Ctrl+A+B").is_err());
+        assert!(Hotkey::parse("Ctrl+Enter").is_err());
+        assert!(Hotkey::parse("Ctrl+Alt").is_err());
+    }
+}
@@ -3,13 +3,13 @@ use anyhow::{anyhow, Result};
 use std::path::PathBuf;
 use image::GenericImageView;
 
-/// Open Windows file dialog to select a PNG file
+/// Open Windows file dialog to select a PNG (or animated GIF/APNG) file
 #[cfg(windows)]
 pub fn open_image_picker() -> Result<PathBuf> {
     use rfd::FileDialog;
     
     let file = FileDialog::new()
-        .add_filter("PNG Image", &["png"])
+        .add_filter("Crosshair Image", &["png", "gif"])
         .add_filter("All Files", &["*"])
         .pick_file();
 
@@ -21,6 +21,24 @@ pub fn open_image_picker() -> Result<PathBuf> {
     Err(anyhow!("File picker only supported on Windows"))
 }
 
+/// Open an Explorer window on the image's folder with the file selected
+#[cfg(windows)]
+pub fn reveal_in_explorer(path: &std::path::Path) -> Result<()> {
+    use std::os::windows::process::CommandExt;
+
+    // explorer.exe parses its own command line, so the path is quoted by hand
+    std::process::Command::new("explorer.exe")
+        .raw_arg(format!("/select,\"{}\"", path.display()))
+        .spawn()
+        .map_err(|e| anyhow!("Failed to open Explorer: {}", e))?;
+    Ok(())
+}
+
+#[cfg(not(windows))]
+pub fn reveal_in_explorer(_path: &std::path::Path) -> Result<()> {
+    Err(anyhow!("Show in folder only supported on Windows"))
+}
+
 /// Validate that the selected image is 100x100 pixels
 pub fn validate_crosshair_image(path: &PathBuf) -> Result<()> {
     let reader = image::io::Reader::open(path)
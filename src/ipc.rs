@@ -1,5 +1,9 @@
 /// Inter-Process Communication between GUI and System Tray
+/// (plus the named pipe a running GUI answers `--status` queries on)
 use std::sync::mpsc::{Sender, Receiver, channel};
+use std::sync::Mutex;
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
 use crate::profile::Profile;
 
 /// Messages from GUI to Tray
@@ -24,6 +28,8 @@ pub enum TrayToGui {
     DeactivateProfile,
     /// User toggled overlay from tray
     ToggleOverlay,
+    /// Flip the active profile's overlay_enabled and re-apply it
+    ToggleCrosshair,
     /// User requested to open settings/GUI
     OpenSettings,
     /// User requested exit
@@ -64,3 +70,106 @@ pub struct TrayChannels {
     pub from_gui: Receiver<GuiToTray>,
     pub to_gui: Sender<TrayToGui>,
 }
+
+/// Pipe a running GUI listens on; `--status` connects and reads one ActiveProfileIs reply
+pub const STATUS_PIPE: &str = r"\\.\pipe\EdgeOptimizerStatus";
+
+/// Reply to a status query, taken from the GUI's in-memory state
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActiveProfileIs {
+    pub active_profile: Option<String>,
+    pub overlay_visible: bool,
+}
+
+/// What the status pipe answers with, kept current by the GUI
+static LIVE_STATUS: Mutex<Option<ActiveProfileIs>> = Mutex::new(None);
+
+/// Update the answer to status queries (called by the GUI whenever activation changes)
+pub fn set_live_status(status: ActiveProfileIs) {
+    if let Ok(mut live) = LIVE_STATUS.lock() {
+        *live = Some(status);
+    }
+}
+
+/// Answer status queries from a background thread for as long as the GUI runs
+#[cfg(windows)]
+pub fn spawn_status_server() {
+    use std::io::Write;
+    use std::os::windows::io::{FromRawHandle, RawHandle};
+    use windows::core::PCWSTR;
+    use windows::Win32::Foundation::ERROR_PIPE_CONNECTED;
+    use windows::Win32::Storage::FileSystem::{FlushFileBuffers, PIPE_ACCESS_OUTBOUND};
+    use windows::Win32::System::Pipes::{
+        ConnectNamedPipe, CreateNamedPipeW, PIPE_TYPE_BYTE, PIPE_UNLIMITED_INSTANCES, PIPE_WAIT,
+    };
+
+    std::thread::spawn(|| {
+        let name: Vec<u16> = STATUS_PIPE.encode_utf16().chain(Some(0)).collect();
+        loop {
+            let pipe = unsafe {
+                CreateNamedPipeW(
+                    PCWSTR(name.as_ptr()),
+                    PIPE_ACCESS_OUTBOUND,
+                    PIPE_TYPE_BYTE | PIPE_WAIT,
+                    PIPE_UNLIMITED_INSTANCES,
+                    4096,
+                    0,
+                    0,
+                    None,
+                )
+            };
+            if pipe.is_invalid() {
+                println!("[IPC] Failed to create the status pipe, --status will not see this instance");
+                return;
+            }
+
+            // A client that connected before this call shows up as ERROR_PIPE_CONNECTED
+            let connected = match unsafe { ConnectNamedPipe(pipe, None) } {
+                Ok(()) => true,
+                Err(e) => e.code() == ERROR_PIPE_CONNECTED.to_hresult(),
+            };
+
+            // The File owns (and closes) the pipe handle from here on
+            let mut file = unsafe { std::fs::File::from_raw_handle(pipe.0 as RawHandle) };
+            if connected {
+                let status = LIVE_STATUS.lock().ok().and_then(|s| s.clone()).unwrap_or_default();
+                if let Ok(reply) = serde_json::to_vec(&status) {
+                    // Flushed so the reply is delivered before the handle closes
+                    if file.write_all(&reply).is_ok() {
+                        let _ = unsafe { FlushFileBuffers(pipe) };
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[cfg(not(windows))]
+pub fn spawn_status_server() {}
+
+/// Ask the running GUI which profile is active
+/// Errors when no instance is running (nothing is active then)
+pub fn query_status() -> Result<ActiveProfileIs> {
+    use std::io::Read;
+
+    // All pipe instances can be busy for a moment while the server re-creates one
+    let mut attempts = 0;
+    let mut pipe = loop {
+        match std::fs::File::open(STATUS_PIPE) {
+            Ok(pipe) => break pipe,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(anyhow!("Edge Optimizer is not running"));
+            }
+            Err(e) if attempts >= 10 => return Err(anyhow!("Failed to reach Edge Optimizer: {}", e)),
+            Err(_) => {
+                attempts += 1;
+                std::thread::sleep(std::time::Duration::from_millis(50));
+            }
+        }
+    };
+
+    let mut reply = String::new();
+    pipe.read_to_string(&mut reply)
+        .map_err(|e| anyhow!("Failed to read status: {}", e))?;
+    serde_json::from_str(&reply).map_err(|e| anyhow!("Unexpected status reply: {}", e))
+}
@@ -0,0 +1,270 @@
+//! File logging for builds without a console
+//!
+//! Everything in the app logs with println!, which goes nowhere once the exe runs as a
+//! windows-subsystem app. `init` points stdout/stderr at logs/edge-optimizer.log in the
+//! data directory instead, so the existing "[GUI]"/"[TRAY]" lines (and panic messages) end
+//! up somewhere a user can attach to a bug report. The file rotates once per day (at startup
+//! and at UTC midnight for sessions left running) and the last week of logs is kept.
+//! There is no log level to set: println! output has none, so every line is written.
+
+use anyhow::{anyhow, Result};
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Current log file inside the logs directory
+pub const LOG_FILE_NAME: &str = "edge-optimizer.log";
+
+/// Rotated logs to keep besides the current one
+const KEEP_ROTATED_LOGS: usize = 7;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// How often a running session checks whether the day has changed
+const ROTATION_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How much of the end of the log the viewer reads
+const TAIL_BYTES: u64 = 256 * 1024;
+
+/// Severity guessed from a log line (println! output has no levels), used for coloring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+}
+
+/// Classify a line by the words the app's log messages use
+pub fn line_level(line: &str) -> LogLevel {
+    let lower = line.to_lowercase();
+    if lower.contains("error") || lower.contains("failed") || lower.contains("panicked") {
+        LogLevel::Error
+    } else if lower.contains("warn") || lower.contains("skipping") || line.contains('⚠') {
+        LogLevel::Warn
+    } else {
+        LogLevel::Info
+    }
+}
+
+/// `<data dir>/logs`, created if missing
+pub fn log_directory() -> Result<PathBuf> {
+    let dir = crate::config::get_data_directory()?.join("logs");
+    fs::create_dir_all(&dir).map_err(|e| anyhow!("Failed to create log directory: {}", e))?;
+    Ok(dir)
+}
+
+/// Path of the log file currently being written
+pub fn current_log_path() -> Result<PathBuf> {
+    Ok(log_directory()?.join(LOG_FILE_NAME))
+}
+
+/// The last `max_lines` lines of the current log file (only the tail of the file is read)
+pub fn tail(max_lines: usize) -> Result<Vec<String>> {
+    let mut file = fs::File::open(current_log_path()?)
+        .map_err(|e| anyhow!("Failed to open log file: {}", e))?;
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = len.saturating_sub(TAIL_BYTES);
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| anyhow!("Failed to read log file: {}", e))?;
+
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)
+        .map_err(|e| anyhow!("Failed to read log file: {}", e))?;
+    let text = String::from_utf8_lossy(&bytes);
+
+    // Starting mid-file means the first line is probably cut off
+    let mut lines: Vec<&str> = text.lines().skip(usize::from(start > 0)).collect();
+    let excess = lines.len().saturating_sub(max_lines);
+    lines.drain(..excess);
+    Ok(lines.into_iter().map(str::to_string).collect())
+}
+
+/// Rotate old logs, send stdout/stderr to the current log file and keep rotating it daily
+pub fn init() -> Result<()> {
+    let dir = log_directory()?;
+    let now = unix_secs(SystemTime::now());
+    rotate(&dir, now);
+
+    let path = dir.join(LOG_FILE_NAME);
+    let mut file = open_log(&path)?;
+    let _ = writeln!(
+        file,
+        "=== Session started {} (pid {}, v{}) ===",
+        format_date(now),
+        std::process::id(),
+        env!("CARGO_PKG_VERSION")
+    );
+
+    redirect_std_handles(file)?;
+    println!("[Log] Writing to {}", path.display());
+    spawn_daily_rotation(dir, now);
+    Ok(())
+}
+
+fn open_log(path: &Path) -> Result<fs::File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| anyhow!("Failed to open log file: {}", e))
+}
+
+/// Start a new log file when the UTC date changes, for sessions left running overnight
+/// Polled rather than slept until midnight so a PC woken from sleep still rotates
+fn spawn_daily_rotation(dir: PathBuf, started: u64) {
+    std::thread::spawn(move || {
+        let mut day = started / SECONDS_PER_DAY;
+        loop {
+            std::thread::sleep(ROTATION_CHECK_INTERVAL);
+            let now = unix_secs(SystemTime::now());
+            if now / SECONDS_PER_DAY == day {
+                continue;
+            }
+
+            archive_current(&dir, day * SECONDS_PER_DAY);
+            prune_rotated(&dir);
+            day = now / SECONDS_PER_DAY;
+
+            // The previous file's handle is left open: a println! racing with a close would panic
+            let result = open_log(&dir.join(LOG_FILE_NAME)).and_then(|mut file| {
+                let _ = writeln!(file, "=== Log continued {} (pid {}) ===", format_date(now), std::process::id());
+                redirect_std_handles(file)
+            });
+            if let Err(e) = result {
+                println!("[Log] Failed to start a new log file: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(windows)]
+fn redirect_std_handles(file: fs::File) -> Result<()> {
+    use std::os::windows::io::IntoRawHandle;
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::System::Console::{SetStdHandle, STD_ERROR_HANDLE, STD_OUTPUT_HANDLE};
+
+    // Rust's stdout looks the handle up on every write, so this catches all later println!s.
+    // The handle is intentionally leaked - it lives as long as the process.
+    let handle = HANDLE(file.into_raw_handle() as isize);
+    unsafe {
+        SetStdHandle(STD_OUTPUT_HANDLE, handle).map_err(|e| anyhow!("Failed to redirect stdout: {}", e))?;
+        SetStdHandle(STD_ERROR_HANDLE, handle).map_err(|e| anyhow!("Failed to redirect stderr: {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn redirect_std_handles(_file: fs::File) -> Result<()> {
+    // Development builds elsewhere keep logging to the terminal
+    Ok(())
+}
+
+/// Move a log from a previous day aside and drop the oldest rotated logs
+fn rotate(dir: &Path, now: u64) {
+    let current = dir.join(LOG_FILE_NAME);
+
+    if let Ok(modified) = fs::metadata(&current).and_then(|m| m.modified()) {
+        let modified = unix_secs(modified);
+        if modified / SECONDS_PER_DAY != now / SECONDS_PER_DAY {
+            archive_current(dir, modified);
+        }
+    }
+
+    prune_rotated(dir);
+}
+
+/// Rename the current log after the day it was written
+/// std opens files with delete sharing, so this works while the log is still open
+fn archive_current(dir: &Path, written: u64) {
+    let rotated = dir.join(rotated_name(written));
+    if let Err(e) = fs::rename(dir.join(LOG_FILE_NAME), rotated) {
+        println!("[Log] Failed to rotate log: {}", e);
+    }
+}
+
+/// Keep only the newest KEEP_ROTATED_LOGS rotated logs
+fn prune_rotated(dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut rotated: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n != LOG_FILE_NAME && n.starts_with("edge-optimizer.") && n.ends_with(".log"))
+        })
+        .collect();
+
+    // Dates in the names sort chronologically, newest last
+    rotated.sort();
+    let excess = rotated.len().saturating_sub(KEEP_ROTATED_LOGS);
+    for path in rotated.into_iter().take(excess) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// "edge-optimizer.2024-05-01.log" for a log last written on that (UTC) day
+fn rotated_name(unix_secs: u64) -> String {
+    format!("edge-optimizer.{}.log", format_date(unix_secs))
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// UTC calendar date as YYYY-MM-DD (days-to-civil algorithm, no date crate needed)
+fn format_date(unix_secs: u64) -> String {
+    let z = (unix_secs / SECONDS_PER_DAY) as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_714_521_600 + 3600), "2024-05-01");
+        assert_eq!(rotated_name(1_714_521_600), "edge-optimizer.2024-05-01.log");
+    }
+
+    #[test]
+    fn test_archive_and_prune() {
+        let dir = std::env::temp_dir().join(format!("go_log_rotate_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for day in 1..=8 {
+            fs::write(dir.join(format!("edge-optimizer.2024-04-0{}.log", day)), "old").unwrap();
+        }
+        fs::write(dir.join(LOG_FILE_NAME), "yesterday").unwrap();
+
+        archive_current(&dir, 1_714_521_600);
+        prune_rotated(&dir);
+
+        assert!(!dir.join(LOG_FILE_NAME).exists());
+        assert_eq!(fs::read_to_string(dir.join("edge-optimizer.2024-05-01.log")).unwrap(), "yesterday");
+        assert!(!dir.join("edge-optimizer.2024-04-02.log").exists());
+        assert!(dir.join("edge-optimizer.2024-04-03.log").exists());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), KEEP_ROTATED_LOGS);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_line_level() {
+        assert_eq!(line_level("[GUI] Failed to save profiles: denied"), LogLevel::Error);
+        assert_eq!(line_level("[GUI] Skipping audio switch: not connected"), LogLevel::Warn);
+        assert_eq!(line_level("[TRAY] Tray icon created"), LogLevel::Info);
+    }
+}
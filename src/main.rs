@@ -1,16 +1,25 @@
 // #![windows_subsystem = "windows"]  // Temporarily disabled for debugging
 
+mod assets;
+mod audio;
 mod config;
 mod overlay;
 mod process;
+mod notifications;
+mod sound;
+mod hotkey;
+mod optimize;
+mod power;
 mod profile;
 mod tray;
 mod tray_flyout;
 mod gui;
 mod ipc;
+mod logging;
 mod common_apps;
 mod image_picker;
 mod crosshair_overlay;
+mod crosshair_shapes;
 mod flyout;
 
 use anyhow::Result;
@@ -19,17 +28,121 @@ fn main() -> Result<()> {
     // Check command line arguments
     let args: Vec<String> = std::env::args().collect();
     
+    // CLI modes print to the console; the GUI and tray log to a file
+    let cli_mode = args.len() > 1
+        && (args[1] == "--status" || args[1] == "--activate" || args[1] == "--overlay-selftest");
+    if !cli_mode && config::load_config().log_to_file {
+        if let Err(e) = logging::init() {
+            eprintln!("Failed to set up file logging: {}", e);
+        }
+    }
+    
     if args.len() > 1 && args[1] == "--tray-only" {
         // Run in tray-only mode (no GUI)
         run_tray_only()?;
+    } else if args.len() > 1 && args[1] == "--status" {
+        // Print the active profile and exit
+        print_status();
+    } else if args.len() > 2 && args[1] == "--activate" {
+        // Kill a profile's processes headlessly (--dry-run only reports)
+        let dry_run = args.iter().any(|a| a == "--dry-run");
+        let force = args.iter().any(|a| a == "--force");
+        activate_from_cli(&args[2], dry_run, force)?;
+    } else if args.len() > 1 && args[1] == "--overlay-selftest" {
+        // Hidden diagnostic: crosshair start-up latency and loop timing
+        crosshair_overlay::run_self_test().map_err(anyhow::Error::msg)?;
     } else {
         // Run full GUI application with integrated tray
-        gui::run()?;
+        // --start-minimized keeps the window hidden until the tray is used (e.g. launched at login)
+        let start_minimized = args.iter().any(|a| a == "--start-minimized")
+            || config::load_config().start_minimized;
+        gui::run(start_minimized)?;
     }
     
     Ok(())
 }
 
+/// Ask the running GUI for its active profile and print it
+fn print_status() {
+    match ipc::query_status() {
+        Ok(status) => {
+            match status.active_profile {
+                Some(name) => println!("Active profile: {}", name),
+                None => println!("No profile active"),
+            }
+            println!("Crosshair overlay: {}", if status.overlay_visible { "on" } else { "off" });
+        }
+        Err(e) => println!("{} - no profile active", e),
+    }
+}
+
+/// Run a profile's process killing from the command line and print the report
+fn activate_from_cli(profile_name: &str, dry_run: bool, force: bool) -> Result<()> {
+    let data_dir = config::get_data_directory()?;
+    let profiles = profile::load_profiles(&data_dir)?;
+    let profile = profiles
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(profile_name))
+        .ok_or_else(|| anyhow::anyhow!("No profile named '{}'", profile_name))?;
+    
+    let app_config = config::load_config();
+    
+    // Same unsaved-work guard as the GUI; a dry run kills nothing so it never needs --force
+    if !dry_run && !force {
+        let risky = process::running_confirm_targets(&profile.processes_to_kill, &app_config.confirm_before_killing);
+        if !risky.is_empty() {
+            return Err(anyhow::anyhow!(
+                "These apps may have unsaved work: {}. Re-run with --force to close them anyway",
+                risky.join(", ")
+            ));
+        }
+    }
+    
+    let report = process::kill_processes(&profile.processes_to_kill, dry_run);
+    
+    let killed_label = if report.dry_run { "Would kill" } else { "Killed" };
+    println!("{}: {}", killed_label, report.killed.join(", "));
+    println!("Failed: {}", report.failed.join(", "));
+    println!("Not running: {}", report.not_found.join(", "));
+    for (name, reason) in &report.blocklist_skipped {
+        println!("Protected ({}): {}", reason, name);
+    }
+    if report.dry_run {
+        println!("Dry run - nothing was terminated");
+    }
+    
+    Ok(())
+}
+
+/// Send ProfilesUpdated to the tray whenever profiles.json changes on disk
+/// (e.g. saved by a GUI instance), so its menu and flyout stay current
+fn watch_profiles_file(data_dir: std::path::PathBuf, to_tray: std::sync::mpsc::Sender<ipc::GuiToTray>) {
+    let path = data_dir.join("profiles.json");
+    let modified = |path: &std::path::Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    
+    std::thread::spawn(move || {
+        let mut last = modified(path.as_path());
+        loop {
+            std::thread::sleep(std::time::Duration::from_secs(2));
+            let current = modified(path.as_path());
+            if current == last {
+                continue;
+            }
+            last = current;
+            
+            match profile::load_profiles(&data_dir) {
+                Ok(profiles) => {
+                    println!("[TRAY] profiles.json changed, reloading {} profiles", profiles.len());
+                    if to_tray.send(ipc::GuiToTray::ProfilesUpdated(profiles)).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => println!("[TRAY] Failed to reload profiles: {}", e),
+            }
+        }
+    });
+}
+
 /// Run in tray-only mode without GUI
 fn run_tray_only() -> Result<()> {
     // Load configuration
@@ -48,6 +161,9 @@ fn run_tray_only() -> Result<()> {
         from_gui: gui_to_tray_rx,
     };
     
+    // No GUI sends ProfilesUpdated in this mode, so follow profiles.json instead
+    watch_profiles_file(data_dir.clone(), gui_to_tray_tx.clone());
+    
     // Start tray thread with flyout
     tray_flyout::run_tray_flyout_thread(
         channels,
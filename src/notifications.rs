@@ -0,0 +1,98 @@
+//! Desktop notifications for profile activation feedback
+//!
+//! Uses a classic notification-area balloon, which Windows 10/11 shows as a toast.
+//! The balloon gets its own short-lived icon because tray-icon doesn't expose
+//! the window handle of the main tray icon.
+
+/// Body text for an activation notification, e.g. "Killed 5 apps, overlay on"
+pub fn activation_summary(killed: usize, overlay_on: bool) -> String {
+    let apps = if killed == 1 { "app" } else { "apps" };
+    let overlay = if overlay_on { "overlay on" } else { "overlay off" };
+    format!("Killed {} {}, {}", killed, apps, overlay)
+}
+
+/// Show a notification without blocking the caller
+#[cfg(windows)]
+pub fn notify(title: &str, body: &str) {
+    let title = title.to_string();
+    let body = body.to_string();
+    std::thread::spawn(move || unsafe { show_balloon(&title, &body) });
+}
+
+#[cfg(not(windows))]
+pub fn notify(title: &str, body: &str) {
+    println!("[Notify] {}: {}", title, body);
+}
+
+/// Copy a string into a fixed-size, NUL-terminated UTF-16 buffer (truncating)
+#[cfg(windows)]
+fn fill_wide(dst: &mut [u16], text: &str) {
+    let max = dst.len() - 1;
+    for (slot, c) in dst.iter_mut().zip(text.encode_utf16().take(max)) {
+        *slot = c;
+    }
+}
+
+#[cfg(windows)]
+unsafe fn show_balloon(title: &str, body: &str) {
+    use windows::core::w;
+    use windows::Win32::UI::Shell::{
+        Shell_NotifyIconW, NIF_ICON, NIF_INFO, NIF_TIP, NIIF_INFO, NIM_ADD, NIM_DELETE,
+        NOTIFYICONDATAW,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{
+        CreateWindowExW, DestroyWindow, LoadIconW, HWND_MESSAGE, IDI_APPLICATION,
+        WINDOW_EX_STYLE, WINDOW_STYLE,
+    };
+
+    // Message-only window to own the temporary icon
+    let hwnd = CreateWindowExW(
+        WINDOW_EX_STYLE(0),
+        w!("STATIC"),
+        w!(""),
+        WINDOW_STYLE(0),
+        0, 0, 0, 0,
+        HWND_MESSAGE,
+        None,
+        None,
+        None,
+    );
+    if hwnd.0 == 0 {
+        println!("[Notify] Failed to create notification window");
+        return;
+    }
+
+    let mut data = NOTIFYICONDATAW {
+        cbSize: std::mem::size_of::<NOTIFYICONDATAW>() as u32,
+        hWnd: hwnd,
+        uID: 1,
+        uFlags: NIF_ICON | NIF_TIP | NIF_INFO,
+        hIcon: LoadIconW(None, IDI_APPLICATION).unwrap_or_default(),
+        dwInfoFlags: NIIF_INFO,
+        ..Default::default()
+    };
+    fill_wide(&mut data.szTip, "Gaming Optimizer");
+    fill_wide(&mut data.szInfoTitle, title);
+    fill_wide(&mut data.szInfo, body);
+
+    if Shell_NotifyIconW(NIM_ADD, &data).as_bool() {
+        // Long enough for the toast to be seen; it stays in Action Center afterwards
+        std::thread::sleep(std::time::Duration::from_secs(6));
+        let _ = Shell_NotifyIconW(NIM_DELETE, &data);
+    } else {
+        println!("[Notify] Shell_NotifyIconW failed");
+    }
+
+    let _ = DestroyWindow(hwnd);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activation_summary() {
+        assert_eq!(activation_summary(5, true), "Killed 5 apps, overlay on");
+        assert_eq!(activation_summary(1, false), "Killed 1 app, overlay off");
+    }
+}
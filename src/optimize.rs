@@ -0,0 +1,119 @@
+use crate::process::{normalize_process_name, protection_reason};
+use sysinfo::System;
+
+/// Report of a working set trim pass
+#[derive(Debug, Clone, Default)]
+pub struct TrimReport {
+    pub trimmed: usize,
+    pub access_denied: usize,
+    pub skipped_protected: usize,
+    pub reclaimed_kb: u64,
+}
+
+impl TrimReport {
+    /// Short summary for the status bar, e.g. "Freed 512 MB from 40 processes"
+    pub fn summary(&self) -> String {
+        format!(
+            "Freed {} MB from {} processes",
+            self.reclaimed_kb / 1024,
+            self.trimmed
+        )
+    }
+}
+
+/// Whether `process_name` is one of the `keep` processes (case-insensitive, ".exe" optional)
+fn is_kept(process_name: &str, keep: &[String]) -> bool {
+    let normalized = normalize_process_name(process_name);
+    keep.iter().any(|k| normalize_process_name(k) == normalized)
+}
+
+/// Trim the working sets of all non-protected processes
+/// Pages are only moved to the standby list, so processes fault them back in as needed.
+/// This app and every process named in `keep` (e.g. the game) are left alone.
+/// Processes we cannot open (elevated, system) are counted and skipped.
+pub fn trim_working_sets(keep: &[String]) -> TrimReport {
+    let mut report = TrimReport::default();
+    let mut sys = System::new();
+    sys.refresh_processes();
+
+    let own_pid = std::process::id();
+    let mut before: Vec<(u32, u64)> = Vec::new();
+
+    for (pid, process) in sys.processes() {
+        if pid.as_u32() == own_pid || protection_reason(process.name()).is_some() || is_kept(process.name(), keep) {
+            report.skipped_protected += 1;
+            continue;
+        }
+
+        if trim_process(pid.as_u32()) {
+            report.trimmed += 1;
+            before.push((pid.as_u32(), process.memory()));
+        } else {
+            report.access_denied += 1;
+        }
+    }
+
+    // Measure again to see how much resident memory was released
+    sys.refresh_processes();
+    for (pid, memory_before) in before {
+        if let Some(process) = sys.process(sysinfo::Pid::from_u32(pid)) {
+            report.reclaimed_kb += memory_before.saturating_sub(process.memory()) / 1024;
+        }
+    }
+
+    report
+}
+
+#[cfg(windows)]
+fn trim_process(pid: u32) -> bool {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, SetProcessWorkingSetSize, PROCESS_QUERY_LIMITED_INFORMATION,
+        PROCESS_SET_QUOTA,
+    };
+
+    unsafe {
+        let handle = match OpenProcess(
+            PROCESS_SET_QUOTA | PROCESS_QUERY_LIMITED_INFORMATION,
+            false,
+            pid,
+        ) {
+            Ok(handle) => handle,
+            Err(_) => return false,
+        };
+
+        // (-1, -1) empties the working set, same as EmptyWorkingSet
+        let trimmed = SetProcessWorkingSetSize(handle, usize::MAX, usize::MAX).is_ok();
+        let _ = CloseHandle(handle);
+        trimmed
+    }
+}
+
+#[cfg(not(windows))]
+fn trim_process(_pid: u32) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_report_summary() {
+        let report = TrimReport {
+            trimmed: 3,
+            access_denied: 1,
+            skipped_protected: 2,
+            reclaimed_kb: 2048,
+        };
+        assert_eq!(report.summary(), "Freed 2 MB from 3 processes");
+    }
+
+    #[test]
+    fn test_is_kept() {
+        let keep = vec!["VALORANT.exe".to_string()];
+        assert!(is_kept("valorant.exe", &keep));
+        assert!(is_kept("Valorant", &keep));
+        assert!(!is_kept("discord.exe", &keep));
+    }
+}
@@ -0,0 +1,179 @@
+//! Windows power plan listing and switching
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+/// Power scheme GUID, stored as "381b4222-f694-41f0-9685-ff5bb260df2e"
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct PowerPlanGuid(pub String);
+
+impl PowerPlanGuid {
+    /// Canonical lowercase hyphenated form of a 128-bit GUID value
+    pub fn from_u128(value: u128) -> Self {
+        let hex = format!("{:032x}", value);
+        PowerPlanGuid(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        ))
+    }
+
+    /// Parse back to a 128-bit value (accepts upper/lower case, with or without braces)
+    pub fn to_u128(&self) -> Option<u128> {
+        let hex: String = self.0.chars().filter(|c| c.is_ascii_hexdigit()).collect();
+        if hex.len() != 32 {
+            return None;
+        }
+        u128::from_str_radix(&hex, 16).ok()
+    }
+}
+
+/// An installed power plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PowerPlan {
+    pub guid: PowerPlanGuid,
+    pub name: String,
+}
+
+impl std::fmt::Display for PowerPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(windows)]
+fn guid_to_plan_guid(guid: &windows::core::GUID) -> PowerPlanGuid {
+    let value = ((guid.data1 as u128) << 96)
+        | ((guid.data2 as u128) << 80)
+        | ((guid.data3 as u128) << 64)
+        | u64::from_be_bytes(guid.data4) as u128;
+    PowerPlanGuid::from_u128(value)
+}
+
+/// List the power plans installed on this machine
+#[cfg(windows)]
+pub fn list_power_plans() -> Result<Vec<PowerPlan>> {
+    use windows::core::GUID;
+    use windows::Win32::System::Power::{PowerEnumerate, PowerReadFriendlyName, ACCESS_SCHEME};
+    use windows::Win32::System::Registry::HKEY;
+
+    let mut plans = Vec::new();
+
+    unsafe {
+        for index in 0.. {
+            let mut guid = GUID::zeroed();
+            let mut size = std::mem::size_of::<GUID>() as u32;
+            let result = PowerEnumerate(
+                HKEY::default(),
+                None,
+                None,
+                ACCESS_SCHEME,
+                index,
+                Some(&mut guid as *mut GUID as *mut u8),
+                &mut size,
+            );
+            // ERROR_NO_MORE_ITEMS ends the enumeration
+            if result.is_err() {
+                break;
+            }
+
+            // First call gets the size, second fills a UTF-16 buffer
+            let mut name_size = 0u32;
+            let _ = PowerReadFriendlyName(HKEY::default(), Some(&guid as *const GUID), None, None, None, &mut name_size);
+            let mut buffer = vec![0u16; (name_size as usize / 2).max(1)];
+            let name = if PowerReadFriendlyName(
+                HKEY::default(),
+                Some(&guid as *const GUID),
+                None,
+                None,
+                Some(buffer.as_mut_ptr() as *mut u8),
+                &mut name_size,
+            )
+            .is_ok()
+            {
+                let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+                String::from_utf16_lossy(&buffer[..len])
+            } else {
+                "Unnamed plan".to_string()
+            };
+
+            plans.push(PowerPlan { guid: guid_to_plan_guid(&guid), name });
+        }
+    }
+
+    if plans.is_empty() {
+        return Err(anyhow!("No power plans found"));
+    }
+    Ok(plans)
+}
+
+#[cfg(not(windows))]
+pub fn list_power_plans() -> Result<Vec<PowerPlan>> {
+    Err(anyhow!("Power plans are only supported on Windows"))
+}
+
+/// GUID of the currently active power plan
+#[cfg(windows)]
+pub fn active_power_plan() -> Result<PowerPlanGuid> {
+    use windows::core::GUID;
+    use windows::Win32::Foundation::{LocalFree, HLOCAL};
+    use windows::Win32::System::Power::PowerGetActiveScheme;
+    use windows::Win32::System::Registry::HKEY;
+
+    unsafe {
+        let mut active: *mut GUID = std::ptr::null_mut();
+        PowerGetActiveScheme(HKEY::default(), &mut active)
+            .ok()
+            .map_err(|e| anyhow!("Failed to read active power plan: {}", e))?;
+        let guid = guid_to_plan_guid(&*active);
+        let _ = LocalFree(HLOCAL(active as _));
+        Ok(guid)
+    }
+}
+
+#[cfg(not(windows))]
+pub fn active_power_plan() -> Result<PowerPlanGuid> {
+    Err(anyhow!("Power plans are only supported on Windows"))
+}
+
+/// Make the given plan active
+#[cfg(windows)]
+pub fn set_active_power_plan(plan: &PowerPlanGuid) -> Result<()> {
+    use windows::core::GUID;
+    use windows::Win32::System::Power::PowerSetActiveScheme;
+    use windows::Win32::System::Registry::HKEY;
+
+    let value = plan.to_u128().ok_or_else(|| anyhow!("Invalid power plan GUID: {}", plan.0))?;
+    let guid = GUID::from_u128(value);
+
+    unsafe {
+        PowerSetActiveScheme(HKEY::default(), Some(&guid as *const GUID))
+            .ok()
+            .map_err(|e| anyhow!("Failed to switch power plan: {}", e))
+    }
+}
+
+#[cfg(not(windows))]
+pub fn set_active_power_plan(_plan: &PowerPlanGuid) -> Result<()> {
+    Err(anyhow!("Power plans are only supported on Windows"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_plan_guid_round_trip() {
+        let balanced = PowerPlanGuid::from_u128(0x381b4222_f694_41f0_9685_ff5bb260df2e);
+        assert_eq!(balanced.0, "381b4222-f694-41f0-9685-ff5bb260df2e");
+        assert_eq!(balanced.to_u128(), Some(0x381b4222_f694_41f0_9685_ff5bb260df2e));
+
+        let braced = PowerPlanGuid("{8C5E7FDA-E8BF-4A96-9A85-A6E23A8C635C}".to_string());
+        assert_eq!(braced.to_u128(), Some(0x8c5e7fda_e8bf_4a96_9a85_a6e23a8c635c));
+        assert_eq!(PowerPlanGuid("not-a-guid".to_string()).to_u128(), None);
+    }
+}
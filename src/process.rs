@@ -1,3 +1,8 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 use sysinfo::System;
 
 /// Information about a running process
@@ -15,17 +20,98 @@ pub struct KillReport {
     pub killed: Vec<String>,
     pub failed: Vec<String>,
     pub not_found: Vec<String>,
-    pub blocklist_skipped: Vec<String>,
+    pub blocklist_skipped: Vec<(String, ProtectionReason)>,
+    /// Nothing was terminated - `killed` lists what *would* have been killed
+    pub dry_run: bool,
+}
+
+/// Why a process was skipped instead of killed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProtectionReason {
+    /// Built-in critical Windows process
+    BuiltInCritical,
+}
+
+impl fmt::Display for ProtectionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProtectionReason::BuiltInCritical => write!(f, "system"),
+        }
+    }
 }
 
 impl KillReport {
-    fn new() -> Self {
+    fn new(dry_run: bool) -> Self {
         KillReport {
             killed: Vec::new(),
             failed: Vec::new(),
             not_found: Vec::new(),
             blocklist_skipped: Vec::new(),
+            dry_run,
+        }
+    }
+
+    /// Full plain-text report with one section per outcome, for pasting into a bug report
+    pub fn to_text(&self) -> String {
+        let protected: Vec<String> = self
+            .blocklist_skipped
+            .iter()
+            .map(|(name, reason)| format!("{} (protected by {})", name, reason))
+            .collect();
+        let sections = [
+            (if self.dry_run { "Would kill" } else { "Killed" }, &self.killed),
+            ("Failed", &self.failed),
+            ("Not running", &self.not_found),
+            ("Skipped", &protected),
+        ];
+
+        let mut text = String::new();
+        for (title, names) in sections {
+            text.push_str(&format!("{} ({}):\n", title, names.len()));
+            if names.is_empty() {
+                text.push_str("  (none)\n");
+            }
+            for name in names {
+                text.push_str(&format!("  - {}\n", name));
+            }
         }
+        if self.dry_run {
+            text.push_str("Dry run - nothing was terminated\n");
+        }
+        text
+    }
+}
+
+/// Windows process priority class
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriorityClass {
+    High,
+    AboveNormal,
+    Normal,
+    BelowNormal,
+    Idle,
+}
+
+impl PriorityClass {
+    pub const ALL: [PriorityClass; 5] = [
+        PriorityClass::High,
+        PriorityClass::AboveNormal,
+        PriorityClass::Normal,
+        PriorityClass::BelowNormal,
+        PriorityClass::Idle,
+    ];
+}
+
+impl fmt::Display for PriorityClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PriorityClass::High => "High",
+            PriorityClass::AboveNormal => "Above Normal",
+            PriorityClass::Normal => "Normal",
+            PriorityClass::BelowNormal => "Below Normal",
+            PriorityClass::Idle => "Idle",
+        };
+        write!(f, "{}", name)
     }
 }
 
@@ -52,8 +138,17 @@ fn is_protected(process_name: &str) -> bool {
         .any(|protected| protected.to_lowercase() == name_lower)
 }
 
+/// Why a process can't be killed, if it is protected
+pub fn protection_reason(process_name: &str) -> Option<ProtectionReason> {
+    if is_protected(process_name) || is_protected(&normalize_process_name(process_name)) {
+        Some(ProtectionReason::BuiltInCritical)
+    } else {
+        None
+    }
+}
+
 /// Normalize process name for matching (case-insensitive, strips .exe if present)
-fn normalize_process_name(name: &str) -> String {
+pub(crate) fn normalize_process_name(name: &str) -> String {
     let lower = name.to_lowercase();
     if lower.ends_with(".exe") {
         lower[..lower.len() - 4].to_string()
@@ -62,88 +157,330 @@ fn normalize_process_name(name: &str) -> String {
     }
 }
 
-/// List all running processes
-pub fn list_processes() -> Vec<ProcessInfo> {
-    let mut sys = System::new_all();
-    sys.refresh_all();
+/// Keeps one `System` alive so process refreshes are incremental and CPU% has a baseline
+/// sysinfo computes CPU usage from the difference between two samples, so a fresh
+/// `System` reports 0% for everything on its first refresh.
+pub struct ProcessSampler {
+    sys: System,
+    cpu_count: f32,
+}
+
+impl ProcessSampler {
+    /// Create a sampler with a primed CPU baseline (blocks for sysinfo's minimum interval)
+    pub fn new() -> Self {
+        let mut sys = System::new();
+        sys.refresh_processes();
+        std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
 
-    let mut processes = Vec::new();
+        let cpu_count = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1) as f32;
 
-    for (pid, process) in sys.processes() {
-        processes.push(ProcessInfo {
-            pid: pid.as_u32(),
-            name: process.name().to_string(),
-            memory_kb: process.memory() / 1024,
-            cpu_percent: process.cpu_usage(),
-        });
+        ProcessSampler { sys, cpu_count }
     }
 
-    // Sort by name for easier viewing
-    processes.sort_by(|a, b| a.name.cmp(&b.name));
+    /// Refresh in place (new processes are added, exited ones dropped) and list them
+    /// CPU% is relative to the whole machine, like Task Manager, not per core
+    pub fn list(&mut self) -> Vec<ProcessInfo> {
+        self.sys.refresh_processes();
+
+        let mut processes: Vec<ProcessInfo> = self
+            .sys
+            .processes()
+            .iter()
+            .map(|(pid, process)| ProcessInfo {
+                pid: pid.as_u32(),
+                name: process.name().to_string(),
+                memory_kb: process.memory() / 1024,
+                cpu_percent: process.cpu_usage() / self.cpu_count,
+            })
+            .collect();
+
+        // Sort by name for easier viewing
+        processes.sort_by(|a, b| a.name.cmp(&b.name));
+
+        processes
+    }
 
-    processes
+    /// Refresh and check whether any instance of the named process is running
+    pub fn is_running(&mut self, process_name: &str) -> bool {
+        self.sys.refresh_processes();
+        let target = normalize_process_name(process_name);
+        self.sys
+            .processes()
+            .values()
+            .any(|process| normalize_process_name(process.name()) == target)
+    }
+}
+
+impl Default for ProcessSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Targets on the confirm-before-killing list that are running right now
+/// Activation (GUI and CLI) asks before killing these since they may hold unsaved work
+pub fn running_confirm_targets(process_names: &[String], confirm_list: &[String]) -> Vec<String> {
+    let mut sys = System::new();
+    sys.refresh_processes();
+    let running: HashSet<String> = sys
+        .processes()
+        .values()
+        .map(|process| normalize_process_name(process.name()))
+        .collect();
+    confirm_targets(process_names, confirm_list, &running)
+}
+
+/// Resource use of all instances of one program (e.g. every chrome.exe together)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessUsage {
+    pub name: String,
+    pub instances: usize,
+    pub cpu_percent: f32,
+    pub memory_kb: u64,
 }
 
-/// Kill processes by name
-/// Returns a detailed report of what happened
-pub fn kill_processes(process_names: &[String]) -> KillReport {
-    let mut report = KillReport::new();
+/// The heaviest programs in a process snapshot, skipping anything that can't be killed
+/// Ranked by their combined share of the snapshot's CPU and memory, so a RAM hog and a
+/// CPU hog can both make the list
+pub fn top_consumers(processes: &[ProcessInfo], limit: usize) -> Vec<ProcessUsage> {
+    let mut usage: Vec<ProcessUsage> = Vec::new();
+    for process in processes {
+        if protection_reason(&process.name).is_some() {
+            continue;
+        }
+        match usage.iter_mut().find(|u| u.name.eq_ignore_ascii_case(&process.name)) {
+            Some(existing) => {
+                existing.instances += 1;
+                existing.cpu_percent += process.cpu_percent;
+                existing.memory_kb += process.memory_kb;
+            }
+            None => usage.push(ProcessUsage {
+                name: process.name.clone(),
+                instances: 1,
+                cpu_percent: process.cpu_percent,
+                memory_kb: process.memory_kb,
+            }),
+        }
+    }
+
+    let total_cpu = usage.iter().map(|u| u.cpu_percent).sum::<f32>().max(f32::EPSILON);
+    let total_memory = usage.iter().map(|u| u.memory_kb).sum::<u64>().max(1) as f32;
+    let share = |u: &ProcessUsage| u.cpu_percent / total_cpu + u.memory_kb as f32 / total_memory;
+
+    usage.sort_by(|a, b| share(b).total_cmp(&share(a)));
+    usage.truncate(limit);
+    usage
+}
+
+/// Targets that are both on the confirm list and in `running` (normalized names)
+fn confirm_targets(process_names: &[String], confirm_list: &[String], running: &HashSet<String>) -> Vec<String> {
+    process_names
+        .iter()
+        .filter(|name| {
+            let normalized = normalize_process_name(name);
+            running.contains(&normalized)
+                && confirm_list.iter().any(|c| normalize_process_name(c) == normalized)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Kill processes by name, skipping protected processes
+/// Returns a detailed report of what happened; with `dry_run` nothing is terminated
+pub fn kill_processes(process_names: &[String], dry_run: bool) -> KillReport {
+    let mut report = KillReport::new(dry_run);
     let mut sys = System::new_all();
     sys.refresh_all();
 
-    for target_name in process_names {
-        let target_normalized = normalize_process_name(target_name);
+    // Resolve every target to its running instances up front (blocklist first, nothing spawned for it)
+    let mut targets: Vec<&String> = Vec::new();
+    let mut jobs: Vec<(usize, &sysinfo::Process)> = Vec::new();
 
-        // Check if process is protected
-        if is_protected(&target_normalized) || is_protected(target_name) {
-            report.blocklist_skipped.push(target_name.clone());
+    for target_name in process_names {
+        if let Some(reason) = protection_reason(target_name) {
+            report.blocklist_skipped.push((target_name.clone(), reason));
             continue;
         }
 
-        // Find all processes matching this name
-        let mut found_any = false;
-        let mut killed_any = false;
-        let mut failed_any = false;
+        let target_index = targets.len();
+        targets.push(target_name);
 
-        for (_pid, process) in sys.processes() {
+        let target_normalized = normalize_process_name(target_name);
+        for process in sys.processes().values() {
             let process_name = process.name();
-            let process_normalized = normalize_process_name(process_name);
 
             // Match either with or without .exe extension
-            if process_normalized == target_normalized
+            if normalize_process_name(process_name) == target_normalized
                 || process_name.to_lowercase() == target_name.to_lowercase()
             {
-                found_any = true;
-
-                // Attempt to kill the process
-                if process.kill() {
-                    killed_any = true;
-                } else {
-                    failed_any = true;
-                }
+                jobs.push((target_index, process));
             }
         }
+    }
+
+    // Terminate instances in parallel (a dry run counts every match as killable)
+    let results = if dry_run {
+        vec![true; jobs.len()]
+    } else {
+        kill_in_parallel(&jobs)
+    };
+
+    // Tally per target in the order the targets were given
+    for (target_index, target_name) in targets.iter().enumerate() {
+        let outcomes: Vec<bool> = jobs
+            .iter()
+            .zip(&results)
+            .filter(|((index, _), _)| *index == target_index)
+            .map(|(_, &killed)| killed)
+            .collect();
+        let killed_any = outcomes.iter().any(|&killed| killed);
+        let failed_any = outcomes.iter().any(|&killed| !killed);
 
-        // Record result for this process name
         if killed_any && !failed_any {
-            report.killed.push(target_name.clone());
+            report.killed.push((*target_name).clone());
         } else if killed_any && failed_any {
             // Some instances killed, some failed
             report.killed.push(format!("{} (partial)", target_name));
             report.failed.push(format!("{} (partial)", target_name));
         } else if failed_any {
-            report.failed.push(target_name.clone());
-        } else if !found_any {
-            report.not_found.push(target_name.clone());
+            report.failed.push((*target_name).clone());
+        } else {
+            report.not_found.push((*target_name).clone());
         }
     }
 
-    // Refresh system info after killing
-    sys.refresh_all();
-
     report
 }
 
+/// Call `kill()` on each process from a small pool of scoped threads
+/// Results line up with `jobs`; TerminateProcess per PID is independent so order doesn't matter
+fn kill_in_parallel(jobs: &[(usize, &sysinfo::Process)]) -> Vec<bool> {
+    if jobs.is_empty() {
+        return Vec::new();
+    }
+
+    let workers = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+        .min(jobs.len());
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(vec![false; jobs.len()]);
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some((_, process)) = jobs.get(index) else {
+                    break;
+                };
+                let killed = process.kill();
+                if let Ok(mut results) = results.lock() {
+                    results[index] = killed;
+                }
+            });
+        }
+    });
+
+    results.into_inner().unwrap_or_default()
+}
+
+/// Set the priority class of every running instance of a process
+/// Returns the (pid, original class) of each instance adjusted, for `restore_priorities`
+/// (protected processes are never touched)
+pub fn set_priority(process_name: &str, class: PriorityClass) -> Vec<(u32, PriorityClass)> {
+    if is_protected(process_name) || is_protected(&normalize_process_name(process_name)) {
+        return Vec::new();
+    }
+
+    let target_normalized = normalize_process_name(process_name);
+    let mut sys = System::new();
+    sys.refresh_processes();
+
+    sys.processes()
+        .iter()
+        .filter(|(_, process)| normalize_process_name(process.name()) == target_normalized)
+        .filter_map(|(pid, _)| {
+            let pid = pid.as_u32();
+            // Leave alone anything whose current class can't be read back later
+            let original = pid_priority(pid)?;
+            set_pid_priority(pid, class).then_some((pid, original))
+        })
+        .collect()
+}
+
+/// Put processes changed by `set_priority` back to their original class
+/// Returns how many were restored (processes that have since exited are skipped)
+pub fn restore_priorities(changes: &[(u32, PriorityClass)]) -> usize {
+    changes
+        .iter()
+        .filter(|(pid, original)| set_pid_priority(*pid, *original))
+        .count()
+}
+
+#[cfg(windows)]
+fn class_flags(class: PriorityClass) -> windows::Win32::System::Threading::PROCESS_CREATION_FLAGS {
+    use windows::Win32::System::Threading::{
+        ABOVE_NORMAL_PRIORITY_CLASS, BELOW_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS,
+        IDLE_PRIORITY_CLASS, NORMAL_PRIORITY_CLASS,
+    };
+
+    match class {
+        PriorityClass::High => HIGH_PRIORITY_CLASS,
+        PriorityClass::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+        PriorityClass::Normal => NORMAL_PRIORITY_CLASS,
+        PriorityClass::BelowNormal => BELOW_NORMAL_PRIORITY_CLASS,
+        PriorityClass::Idle => IDLE_PRIORITY_CLASS,
+    }
+}
+
+#[cfg(windows)]
+fn pid_priority(pid: u32) -> Option<PriorityClass> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        GetPriorityClass, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let raw = GetPriorityClass(handle);
+        let _ = CloseHandle(handle);
+
+        // Realtime (or a failed query, 0) has no PriorityClass equivalent
+        PriorityClass::ALL
+            .into_iter()
+            .find(|class| class_flags(*class).0 == raw)
+    }
+}
+
+#[cfg(not(windows))]
+fn pid_priority(_pid: u32) -> Option<PriorityClass> {
+    None
+}
+
+#[cfg(windows)]
+fn set_pid_priority(pid: u32, class: PriorityClass) -> bool {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{OpenProcess, SetPriorityClass, PROCESS_SET_INFORMATION};
+
+    unsafe {
+        let handle = match OpenProcess(PROCESS_SET_INFORMATION, false, pid) {
+            Ok(handle) => handle,
+            Err(_) => return false,
+        };
+
+        let result = SetPriorityClass(handle, class_flags(class)).is_ok();
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+#[cfg(not(windows))]
+fn set_pid_priority(_pid: u32, _class: PriorityClass) -> bool {
+    false
+}
+
 /// Check if a process name would be blocked by the safety blocklist
 pub fn would_be_protected(process_name: &str) -> bool {
     is_protected(process_name)
@@ -171,6 +508,13 @@ mod tests {
         assert!(!is_protected("chrome.exe"));
     }
 
+    #[test]
+    fn test_protection_reason() {
+        assert_eq!(protection_reason("csrss.exe"), Some(ProtectionReason::BuiltInCritical));
+        assert_eq!(protection_reason("Explorer.EXE"), Some(ProtectionReason::BuiltInCritical));
+        assert_eq!(protection_reason("discord.exe"), None);
+    }
+
     #[test]
     fn test_would_be_protected() {
         assert!(would_be_protected("dwm.exe"));
@@ -180,17 +524,93 @@ mod tests {
 
     #[test]
     fn test_list_processes() {
-        let processes = list_processes();
+        let mut sampler = ProcessSampler::new();
+        let processes = sampler.list();
         // Should return at least some processes on any system
         assert!(!processes.is_empty());
+        // Refreshing the same sampler keeps working incrementally
+        assert!(!sampler.list().is_empty());
+        assert!(!sampler.is_running("definitely_not_running_12345.exe"));
+    }
+
+    #[test]
+    fn test_set_priority_skips_protected() {
+        assert!(set_priority("csrss.exe", PriorityClass::High).is_empty());
+        assert!(set_priority("Explorer", PriorityClass::Idle).is_empty());
     }
 
     #[test]
     fn test_kill_report_new() {
-        let report = KillReport::new();
+        let report = KillReport::new(false);
         assert!(report.killed.is_empty());
         assert!(report.failed.is_empty());
         assert!(report.not_found.is_empty());
         assert!(report.blocklist_skipped.is_empty());
+        assert!(!report.dry_run);
+    }
+
+    #[test]
+    fn test_kill_processes_dry_run() {
+        let names = vec!["csrss.exe".to_string(), "definitely_not_running_12345.exe".to_string()];
+        let report = kill_processes(&names, true);
+        assert!(report.dry_run);
+        assert!(report.killed.is_empty());
+        assert_eq!(report.not_found, vec!["definitely_not_running_12345.exe".to_string()]);
+        assert_eq!(report.blocklist_skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_confirm_targets() {
+        let targets = vec!["WINWORD.EXE".to_string(), "Discord.exe".to_string(), "EXCEL".to_string()];
+        let confirm = vec!["winword.exe".to_string(), "excel.exe".to_string()];
+        let running: HashSet<String> = ["winword".to_string(), "discord".to_string()].into_iter().collect();
+        // Excel is on the list but not running, Discord is running but not on the list
+        assert_eq!(confirm_targets(&targets, &confirm, &running), vec!["WINWORD.EXE".to_string()]);
+    }
+
+    #[test]
+    fn test_kill_report_to_text() {
+        let mut report = KillReport::new(false);
+        report.killed = vec!["Discord.exe".to_string(), "Spotify.exe".to_string()];
+        report.blocklist_skipped.push(("explorer.exe".to_string(), ProtectionReason::BuiltInCritical));
+
+        let text = report.to_text();
+        assert!(text.starts_with("Killed (2):\n  - Discord.exe\n  - Spotify.exe\n"));
+        assert!(text.contains("Failed (0):\n  (none)\n"));
+        assert!(text.contains("  - explorer.exe (protected by system)\n"));
+        assert!(!text.contains("Dry run"));
+    }
+
+    #[test]
+    fn test_top_consumers() {
+        let info = |name: &str, cpu_percent: f32, memory_kb: u64| ProcessInfo {
+            pid: 0,
+            name: name.to_string(),
+            memory_kb,
+            cpu_percent,
+        };
+        let processes = vec![
+            info("chrome.exe", 5.0, 400_000),
+            info("Chrome.exe", 5.0, 400_000),
+            info("obs64.exe", 30.0, 200_000),
+            info("notepad.exe", 0.0, 10_000),
+            info("explorer.exe", 50.0, 900_000),
+        ];
+
+        let top = top_consumers(&processes, 2);
+        // explorer.exe is protected; chrome's two instances are merged
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].name, "chrome.exe");
+        assert_eq!(top[0].instances, 2);
+        assert_eq!(top[0].memory_kb, 800_000);
+        assert_eq!(top[1].name, "obs64.exe");
+    }
+
+    #[test]
+    fn test_kill_processes_keeps_target_order() {
+        let names: Vec<String> = (0..8).map(|i| format!("not_running_{}.exe", i)).collect();
+        let report = kill_processes(&names, false);
+        assert_eq!(report.not_found, names);
+        assert!(report.killed.is_empty() && report.failed.is_empty());
     }
 }
@@ -2,11 +2,28 @@ use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::assets;
+use crate::config::AppConfig;
+use crate::crosshair_shapes::{self, CrosshairKind, CrosshairShape};
+use crate::hotkey::Hotkey;
+use crate::power::PowerPlanGuid;
+use crate::process::PriorityClass;
+use crate::sound;
+
+/// Longest profile name `validate` accepts, in bytes
+pub const MAX_NAME_LEN: usize = 50;
 
 /// Gaming profile containing optimization settings and crosshair configuration
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Profile {
     pub name: String,
+    /// Free-form notes; the first line is shown in the sidebar and flyout
+    #[serde(default)]
+    pub description: String,
+    /// Labels like "FPS" or "Streaming" the sidebar can filter by
+    #[serde(default)]
+    pub tags: Vec<String>,
     pub processes_to_kill: Vec<String>,
     pub crosshair_image_path: Option<String>,
     pub crosshair_x_offset: i32,
@@ -14,34 +31,224 @@ pub struct Profile {
     pub overlay_enabled: bool,
     #[serde(default)]
     pub fan_speed_max: bool,
+    /// Output device (friendly name) made the Windows default on activation
+    #[serde(default)]
+    pub default_audio_device: Option<String>,
+    /// Power plan switched to on activation; the previous plan is restored on deactivation
+    #[serde(default)]
+    pub power_plan: Option<PowerPlanGuid>,
+    /// Trim working sets of background processes on activation
+    #[serde(default)]
+    pub trim_memory: bool,
+    /// Priority classes applied on activation; each process gets its original class back on deactivation
+    #[serde(default)]
+    pub process_priority_rules: Vec<(String, PriorityClass)>,
+    /// Deactivate automatically once this process has run and then exited (e.g. the game)
+    #[serde(default)]
+    pub deactivate_on_process_exit: Option<String>,
+    /// Cue played on activation: a .wav path or a built-in "system:" sound
+    #[serde(default)]
+    pub activation_sound: Option<String>,
+    /// Unix seconds of the last activation
+    #[serde(default)]
+    pub last_activated: Option<u64>,
+    /// Number of times this profile has been activated
+    #[serde(default)]
+    pub activation_count: u64,
+    /// Procedural crosshair drawn when no image is set
+    #[serde(default)]
+    pub crosshair_kind: CrosshairKind,
+    #[serde(default = "default_crosshair_size")]
+    pub crosshair_size: u32,
+    #[serde(default = "default_crosshair_thickness")]
+    pub crosshair_thickness: u32,
+    /// Procedural crosshair color as 0xRRGGBB
+    #[serde(default = "default_crosshair_color")]
+    pub crosshair_color: u32,
+    /// Empty pixels at the center of a Cross crosshair
+    #[serde(default)]
+    pub crosshair_gap: u32,
+    /// Fixed crosshair.exe loop rate in Hz (0 = automatic)
+    #[serde(default)]
+    pub crosshair_refresh_hz: u32,
+    /// Keep forcing the crosshair topmost (needed for fullscreen games)
+    #[serde(default = "default_aggressive_topmost")]
+    pub aggressive_topmost: bool,
+    /// How often the crosshair re-asserts topmost when `aggressive_topmost` is on, in ms
+    #[serde(default = "default_topmost_interval_ms")]
+    pub topmost_interval_ms: u32,
+    /// Also re-assert topmost whenever the foreground window changes
+    #[serde(default)]
+    pub topmost_on_foreground: bool,
+    /// Hide the crosshair while `game_process` isn't the focused window (e.g. alt-tabbed out)
+    #[serde(default)]
+    pub hide_crosshair_when_unfocused: bool,
+    /// The game's executable, e.g. "cs2.exe"
+    #[serde(default)]
+    pub game_process: Option<String>,
+    /// Extra crosshair images drawn on top of the main crosshair (e.g. corner markers)
+    #[serde(default)]
+    pub crosshair_layers: Vec<CrosshairLayer>,
+    /// Saved crosshair looks the cycle shortcut steps through while the profile is active
+    #[serde(default)]
+    pub crosshair_presets: Vec<CrosshairPreset>,
+    /// System-wide shortcut that switches to the next crosshair preset, e.g. "Ctrl+Alt+C"
+    #[serde(default)]
+    pub cycle_crosshair_shortcut: Option<String>,
+}
+
+/// An extra crosshair image, shown in its own overlay window while the profile is active
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CrosshairLayer {
+    pub image_path: String,
+    #[serde(default)]
+    pub x_offset: i32,
+    #[serde(default)]
+    pub y_offset: i32,
+    /// Size relative to the image, in percent
+    #[serde(default = "default_layer_percent")]
+    pub scale_percent: u32,
+    #[serde(default = "default_layer_percent")]
+    pub opacity_percent: u32,
+}
+
+impl CrosshairLayer {
+    /// A layer drawing the image unscaled at screen center
+    pub fn new(image_path: String) -> Self {
+        CrosshairLayer {
+            image_path,
+            x_offset: 0,
+            y_offset: 0,
+            scale_percent: 100,
+            opacity_percent: 100,
+        }
+    }
+
+    /// Offsets, scale and opacity (the image file is checked by `Profile::validate`)
+    pub fn validate(&self) -> Result<()> {
+        if self.x_offset < -500 || self.x_offset > 500 || self.y_offset < -500 || self.y_offset > 500 {
+            return Err(anyhow!("Layer offsets must be between -500 and 500 pixels"));
+        }
+        if self.scale_percent < 10 || self.scale_percent > 400 {
+            return Err(anyhow!("Layer scale must be between 10% and 400%"));
+        }
+        if self.opacity_percent < 5 || self.opacity_percent > 100 {
+            return Err(anyhow!("Layer opacity must be between 5% and 100%"));
+        }
+        Ok(())
+    }
+}
+
+/// Which groups of settings "Copy settings" carries over to other profiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyFields {
+    /// Crosshair look, position, layers, presets and overlay tuning
+    pub crosshair: bool,
+    /// Processes to kill
+    pub processes: bool,
+    pub fan: bool,
+}
+
+/// A saved crosshair (image or procedural shape, plus position) a profile can switch to
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CrosshairPreset {
+    pub name: String,
+    pub image_path: Option<String>,
+    #[serde(default)]
+    pub kind: CrosshairKind,
+    #[serde(default = "default_crosshair_size")]
+    pub size: u32,
+    #[serde(default = "default_crosshair_thickness")]
+    pub thickness: u32,
+    #[serde(default = "default_crosshair_color")]
+    pub color: u32,
+    #[serde(default)]
+    pub gap: u32,
+    #[serde(default)]
+    pub x_offset: i32,
+    #[serde(default)]
+    pub y_offset: i32,
+}
+
+impl CrosshairPreset {
+    /// Same crosshair, ignoring the name
+    fn same_look(&self, other: &CrosshairPreset) -> bool {
+        CrosshairPreset { name: other.name.clone(), ..self.clone() } == *other
+    }
+}
+
+fn default_layer_percent() -> u32 {
+    100
+}
+
+fn default_crosshair_size() -> u32 {
+    crosshair_shapes::DEFAULT_SIZE
+}
+
+fn default_crosshair_thickness() -> u32 {
+    crosshair_shapes::DEFAULT_THICKNESS
+}
+
+fn default_crosshair_color() -> u32 {
+    crosshair_shapes::DEFAULT_COLOR
+}
+
+fn default_aggressive_topmost() -> bool {
+    true
+}
+
+fn default_topmost_interval_ms() -> u32 {
+    100
 }
 
 impl Profile {
-    /// Validate profile data
+    /// First non-empty line of the description, if any
+    pub fn description_summary(&self) -> Option<&str> {
+        self.description.lines().map(str::trim).find(|line| !line.is_empty())
+    }
+
+    /// Whether the profile carries a tag (case-insensitive)
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Validate profile data, including that its image and sound files exist
     pub fn validate(&self) -> Result<()> {
+        self.validate_settings()?;
+
+        if let Some(path) = self.missing_images().first() {
+            return Err(anyhow!(
+                "Crosshair image file does not exist: {}",
+                path
+            ));
+        }
+
+        if let Some(ref cue) = self.activation_sound {
+            sound::validate_cue(cue)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that don't touch the filesystem (name, offsets, shape, shortcut...)
+    /// Used on restore, where a profile's images may not be back in place yet
+    pub fn validate_settings(&self) -> Result<()> {
         // Validate name length (1-50 characters)
-        if self.name.is_empty() || self.name.len() > 50 {
+        if self.name.is_empty() || self.name.len() > MAX_NAME_LEN {
             return Err(anyhow!(
                 "Profile name must be between 1 and 50 characters"
             ));
         }
 
         // Validate crosshair image path if provided
-        if let Some(ref path) = self.crosshair_image_path {
+        if let Some(ref path) = self.crosshair_image_path.as_ref().filter(|p| !assets::is_builtin(p)) {
             let path_obj = Path::new(path);
 
-            // Check if file exists
-            if !path_obj.exists() {
-                return Err(anyhow!(
-                    "Crosshair image file does not exist: {}",
-                    path
-                ));
-            }
-
-            // Check if file has .png extension
-            if path_obj.extension().and_then(|s| s.to_str()) != Some("png") {
+            // PNG, or GIF for animated crosshairs (any case, e.g. "CROSS.PNG")
+            let extension = path_obj.extension().and_then(|s| s.to_str()).unwrap_or("");
+            if !["png", "gif"].iter().any(|ext| extension.eq_ignore_ascii_case(ext)) {
                 return Err(anyhow!(
-                    "Crosshair image must be a PNG file: {}",
+                    "Crosshair image must be a PNG or GIF file: {}",
                     path
                 ));
             }
@@ -59,12 +266,220 @@ impl Profile {
             ));
         }
 
+        // Validate procedural crosshair dimensions
+        if self.crosshair_size < 2 || self.crosshair_size > 100 {
+            return Err(anyhow!(
+                "Crosshair size must be between 2 and 100 pixels"
+            ));
+        }
+        if self.crosshair_thickness < 1 || self.crosshair_thickness > self.crosshair_size {
+            return Err(anyhow!(
+                "Crosshair thickness must be between 1 and the crosshair size"
+            ));
+        }
+        if self.crosshair_gap >= self.crosshair_size {
+            return Err(anyhow!(
+                "Crosshair gap must be smaller than the crosshair size"
+            ));
+        }
+
+        for layer in &self.crosshair_layers {
+            layer.validate()?;
+        }
+
+        if let Some(ref shortcut) = self.cycle_crosshair_shortcut {
+            Hotkey::parse(shortcut)?;
+        }
+
+        if self.hide_crosshair_when_unfocused && self.game_process.is_none() {
+            return Err(anyhow!(
+                "Enter the game's process name to hide the crosshair when it isn't focused"
+            ));
+        }
+
         Ok(())
     }
+
+    /// Crosshair image files (main image and layers) that no longer exist on disk
+    pub fn missing_images(&self) -> Vec<&str> {
+        self.crosshair_image_path
+            .iter()
+            .chain(self.crosshair_layers.iter().map(|layer| &layer.image_path))
+            .map(String::as_str)
+            .filter(|path| !assets::is_builtin(path) && !Path::new(path).exists())
+            .collect()
+    }
+
+    /// The profile's current crosshair as a preset
+    pub fn current_crosshair(&self, name: String) -> CrosshairPreset {
+        CrosshairPreset {
+            name,
+            image_path: self.crosshair_image_path.clone(),
+            kind: self.crosshair_kind,
+            size: self.crosshair_size,
+            thickness: self.crosshair_thickness,
+            color: self.crosshair_color,
+            gap: self.crosshair_gap,
+            x_offset: self.crosshair_x_offset,
+            y_offset: self.crosshair_y_offset,
+        }
+    }
+
+    /// Make a preset the profile's current crosshair
+    pub fn apply_crosshair(&mut self, preset: &CrosshairPreset) {
+        self.crosshair_image_path = preset.image_path.clone();
+        self.crosshair_kind = preset.kind;
+        self.crosshair_size = preset.size;
+        self.crosshair_thickness = preset.thickness;
+        self.crosshair_color = preset.color;
+        self.crosshair_gap = preset.gap;
+        self.crosshair_x_offset = preset.x_offset;
+        self.crosshair_y_offset = preset.y_offset;
+    }
+
+    /// Switch to the preset after the one currently in use (the first if none matches)
+    /// Returns the preset switched to, or None when the profile has no presets
+    pub fn cycle_crosshair_preset(&mut self) -> Option<&CrosshairPreset> {
+        let current = self.current_crosshair(String::new());
+        let next = match self.crosshair_presets.iter().position(|p| p.same_look(&current)) {
+            Some(index) => (index + 1) % self.crosshair_presets.len(),
+            None if self.crosshair_presets.is_empty() => return None,
+            None => 0,
+        };
+        let preset = self.crosshair_presets[next].clone();
+        self.apply_crosshair(&preset);
+        self.crosshair_presets.get(next)
+    }
+
+    /// Overwrite the chosen groups of settings with `source`'s
+    /// Name, notes, tags and usage stats always stay the profile's own
+    pub fn copy_settings_from(&mut self, source: &Profile, fields: CopyFields) {
+        if fields.crosshair {
+            self.apply_crosshair(&source.current_crosshair(String::new()));
+            self.overlay_enabled = source.overlay_enabled;
+            self.crosshair_refresh_hz = source.crosshair_refresh_hz;
+            self.aggressive_topmost = source.aggressive_topmost;
+            self.topmost_interval_ms = source.topmost_interval_ms;
+            self.topmost_on_foreground = source.topmost_on_foreground;
+            self.crosshair_layers = source.crosshair_layers.clone();
+            self.crosshair_presets = source.crosshair_presets.clone();
+        }
+        if fields.processes {
+            self.processes_to_kill = source.processes_to_kill.clone();
+        }
+        if fields.fan {
+            self.fan_speed_max = source.fan_speed_max;
+        }
+    }
+
+    /// Process the crosshair should follow focus of, if hiding on focus loss is enabled
+    pub fn crosshair_focus_process(&self) -> Option<String> {
+        self.game_process.clone().filter(|_| self.hide_crosshair_when_unfocused)
+    }
+
+    /// Procedural crosshair settings as a drawable shape
+    pub fn crosshair_shape(&self) -> CrosshairShape {
+        CrosshairShape {
+            kind: self.crosshair_kind,
+            size: self.crosshair_size,
+            thickness: self.crosshair_thickness,
+            color: self.crosshair_color,
+            gap: self.crosshair_gap,
+        }
+    }
+}
+
+/// Tags from comma-separated editor input: trimmed, without empties or case-insensitive repeats
+pub fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in text.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Current time as Unix seconds
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Short relative age of a Unix timestamp, e.g. "5m ago" or "2d ago"
+pub fn format_relative_time(timestamp: u64, now: u64) -> String {
+    let elapsed = now.saturating_sub(timestamp);
+    match elapsed {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", elapsed / 60),
+        3600..=86399 => format!("{}h ago", elapsed / 3600),
+        _ => format!("{}d ago", elapsed / 86400),
+    }
+}
+
+/// Current on-disk format of profiles.json
+/// v1: bare array of profiles (original format)
+/// v2: `{ "schema_version": 2, "profiles": [...] }`; new fields are filled with defaults
+/// v3: an image-less profile with the overlay on draws the procedural crosshair
+pub const PROFILES_SCHEMA_VERSION: u32 = 3;
+
+/// Versioned wrapper written to profiles.json
+#[derive(Serialize)]
+struct ProfilesFile<'a> {
+    schema_version: u32,
+    profiles: &'a [Profile],
+}
+
+/// Upgrade parsed profiles.json contents to the current schema
+/// Returns the profiles array and the version it was read as
+fn migrate_profiles(value: serde_json::Value) -> Result<(serde_json::Value, u32)> {
+    let (version, mut profiles) = match value {
+        // v1 files are a bare array
+        serde_json::Value::Array(_) => (1, value),
+        serde_json::Value::Object(mut map) => {
+            let version = map
+                .get("schema_version")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(1) as u32;
+            let profiles = map
+                .remove("profiles")
+                .ok_or_else(|| anyhow!("profiles.json has no profiles list"))?;
+            (version, profiles)
+        }
+        _ => return Err(anyhow!("profiles.json has an unexpected format")),
+    };
+
+    if version > PROFILES_SCHEMA_VERSION {
+        return Err(anyhow!(
+            "profiles.json is schema v{} but this version only understands up to v{}",
+            version, PROFILES_SCHEMA_VERSION
+        ));
+    }
+
+    // v1 -> v2 only added optional fields, which serde fills with defaults.
+    // Future breaking changes transform `profiles` here before deserializing.
+
+    // v2 -> v3: older profiles without an image showed no crosshair even with the overlay on.
+    // Turn their overlay off so they don't suddenly show the default shape.
+    if version < 3 {
+        if let serde_json::Value::Array(ref mut items) = profiles {
+            for item in items.iter_mut().filter_map(|item| item.as_object_mut()) {
+                let has_image = item.get("crosshair_image_path").is_some_and(|p| !p.is_null());
+                if !has_image && !item.contains_key("crosshair_kind") {
+                    item.insert("overlay_enabled".to_string(), serde_json::Value::Bool(false));
+                }
+            }
+        }
+    }
+
+    Ok((profiles, version))
 }
 
 /// Load profiles from JSON file in user data directory
 /// Returns empty vector if file doesn't exist (not an error)
+/// Older schema versions are migrated in memory and upgraded on the next save
 pub fn load_profiles(data_dir: &Path) -> Result<Vec<Profile>> {
     let profiles_path = data_dir.join("profiles.json");
 
@@ -77,12 +492,104 @@ pub fn load_profiles(data_dir: &Path) -> Result<Vec<Profile>> {
     let contents = fs::read_to_string(&profiles_path)
         .map_err(|e| anyhow!("Failed to read profiles.json: {}", e))?;
 
-    let profiles: Vec<Profile> = serde_json::from_str(&contents)
-        .map_err(|e| anyhow!("Failed to parse profiles.json: {}", e))?;
+    let (mut profiles, version) = parse_profiles(&contents)?;
+    if version < PROFILES_SCHEMA_VERSION {
+        println!(
+            "[Profiles] Migrated profiles.json from schema v{} to v{} (saved on next write)",
+            version, PROFILES_SCHEMA_VERSION
+        );
+    }
+
+    // A hand-edited file can repeat a name; every lookup by name would silently hit the first
+    for (old, new) in dedupe_profile_names(&mut profiles) {
+        println!("[Profiles] Duplicate profile name '{}' renamed to '{}'", old, new);
+    }
+
+    // Kept as-is so the user can re-pick the image; the GUI flags these profiles
+    for profile in &profiles {
+        for path in profile.missing_images() {
+            println!("[Profiles] Warning: '{}' uses a missing crosshair image: {}", profile.name, path);
+        }
+    }
 
     Ok(profiles)
 }
 
+/// Parse the text of profiles.json, returning the profiles and the file's schema version
+/// Errors say which profile is broken and where, for people editing the file by hand
+fn parse_profiles(contents: &str) -> Result<(Vec<Profile>, u32)> {
+    // serde_json's syntax errors already carry the line and column
+    let value: serde_json::Value = serde_json::from_str(contents)
+        .map_err(|e| anyhow!("Failed to parse profiles.json: {}", e))?;
+
+    let (profiles_value, version) = migrate_profiles(value)?;
+    let serde_json::Value::Array(items) = profiles_value else {
+        return Err(anyhow!("Failed to parse profiles.json: the profiles list is not an array"));
+    };
+
+    let mut profiles = Vec::with_capacity(items.len());
+    for (index, item) in items.into_iter().enumerate() {
+        let name = item.get("name").and_then(|n| n.as_str()).map(str::to_string);
+        match serde_json::from_value::<Profile>(item) {
+            Ok(profile) => profiles.push(profile),
+            Err(e) => {
+                let name = name.map(|n| format!(" ('{}')", n)).unwrap_or_default();
+                let location = typed_error_location(contents)
+                    .map(|(line, column)| format!(" at line {} column {}", line, column))
+                    .unwrap_or_default();
+                return Err(anyhow!(
+                    "Failed to parse profiles.json: profile #{}{}: {}{}",
+                    index + 1, name, e, location
+                ));
+            }
+        }
+    }
+
+    Ok((profiles, version))
+}
+
+/// Line and column of the first field that doesn't fit `Profile`
+/// Values lose their position once parsed, so this re-reads the raw text with types
+fn typed_error_location(contents: &str) -> Option<(usize, usize)> {
+    #[derive(Deserialize)]
+    struct Versioned {
+        #[allow(dead_code)]
+        profiles: Vec<Profile>,
+    }
+
+    let error = if contents.trim_start().starts_with('[') {
+        serde_json::from_str::<Vec<Profile>>(contents).err()?
+    } else {
+        serde_json::from_str::<Versioned>(contents).err()?
+    };
+    Some((error.line(), error.column()))
+}
+
+/// Rename profiles whose name (case-insensitive) was already used earlier in the list
+/// The first occurrence keeps its name, later ones get " (2)", " (3)", ...
+/// Returns (old name, new name) for each rename
+pub fn dedupe_profile_names(profiles: &mut [Profile]) -> Vec<(String, String)> {
+    let mut renamed = Vec::new();
+
+    for i in 0..profiles.len() {
+        if is_profile_name_unique(&profiles[..i], &profiles[i].name, None) {
+            continue;
+        }
+
+        let old = profiles[i].name.clone();
+        let mut n = 2;
+        let mut name = name_with_suffix(&old, &format!(" ({})", n));
+        while !is_profile_name_unique(profiles, &name, Some(i)) {
+            n += 1;
+            name = name_with_suffix(&old, &format!(" ({})", n));
+        }
+        profiles[i].name = name.clone();
+        renamed.push((old, name));
+    }
+
+    renamed
+}
+
 /// Save profiles to JSON file in user data directory
 /// Creates directory if it doesn't exist
 pub fn save_profiles(profiles: &[Profile], data_dir: &Path) -> Result<()> {
@@ -92,8 +599,12 @@ pub fn save_profiles(profiles: &[Profile], data_dir: &Path) -> Result<()> {
 
     let profiles_path = data_dir.join("profiles.json");
 
-    // Serialize to pretty-printed JSON
-    let json = serde_json::to_string_pretty(profiles)
+    // Serialize to pretty-printed JSON, always at the current schema version
+    let file = ProfilesFile {
+        schema_version: PROFILES_SCHEMA_VERSION,
+        profiles,
+    };
+    let json = serde_json::to_string_pretty(&file)
         .map_err(|e| anyhow!("Failed to serialize profiles: {}", e))?;
 
     // Write to file
@@ -103,16 +614,132 @@ pub fn save_profiles(profiles: &[Profile], data_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Full backup written by "Backup": every profile plus the app config
+#[derive(Serialize, Deserialize, Debug)]
+pub struct BackupBundle {
+    /// Unix seconds when the backup was made
+    pub created_at: u64,
+    pub profiles: Vec<Profile>,
+    #[serde(default)]
+    pub config: Option<AppConfig>,
+}
+
+/// Backup file name with a timestamp, e.g. "gaming_optimizer_backup_1700000000.json"
+pub fn backup_file_name() -> String {
+    format!("gaming_optimizer_backup_{}.json", unix_now())
+}
+
+/// Write all profiles and the config into a single backup file
+pub fn export_all_profiles(profiles: &[Profile], config: &AppConfig, path: &Path) -> Result<()> {
+    let bundle = BackupBundle {
+        created_at: unix_now(),
+        profiles: profiles.to_vec(),
+        config: Some(config.clone()),
+    };
+
+    let json = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| anyhow!("Failed to serialize backup: {}", e))?;
+
+    fs::write(path, json)
+        .map_err(|e| anyhow!("Failed to write backup: {}", e))?;
+
+    Ok(())
+}
+
+/// Read a backup file, checking each profile on its own
+/// Returns the bundle with only the valid profiles, plus how many were skipped
+pub fn import_all_profiles(path: &Path) -> Result<(BackupBundle, usize)> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read backup: {}", e))?;
+
+    let value: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| anyhow!("Backup is not valid JSON: {}", e))?;
+
+    let raw_profiles = value
+        .get("profiles")
+        .and_then(|p| p.as_array())
+        .ok_or_else(|| anyhow!("Backup has no profiles list"))?;
+
+    let mut profiles = Vec::new();
+    let mut skipped = 0;
+    for raw in raw_profiles {
+        match serde_json::from_value::<Profile>(raw.clone()) {
+            // Missing images are kept and flagged by the GUI, like on a normal load
+            Ok(profile) if profile.validate_settings().is_ok() => profiles.push(profile),
+            _ => skipped += 1,
+        }
+    }
+    dedupe_profile_names(&mut profiles);
+
+    let bundle = BackupBundle {
+        created_at: value.get("created_at").and_then(|t| t.as_u64()).unwrap_or(0),
+        profiles,
+        config: value.get("config").and_then(|c| serde_json::from_value(c.clone()).ok()),
+    };
+
+    Ok((bundle, skipped))
+}
+
+/// Merge restored profiles into the existing list
+/// On a name collision the restored profile either replaces the existing one,
+/// or is kept alongside it under a "(restored)" name
+pub fn merge_profiles(existing: &mut Vec<Profile>, incoming: Vec<Profile>, replace_collisions: bool) {
+    for mut profile in incoming {
+        let collision = existing
+            .iter()
+            .position(|p| p.name.to_lowercase() == profile.name.to_lowercase());
+
+        match collision {
+            Some(index) if replace_collisions => existing[index] = profile,
+            Some(_) => {
+                let mut name = name_with_suffix(&profile.name, " (restored)");
+                let mut n = 2;
+                while !is_profile_name_unique(existing, &name, None) {
+                    name = name_with_suffix(&profile.name, &format!(" (restored) {}", n));
+                    n += 1;
+                }
+                profile.name = name;
+                existing.push(profile);
+            }
+            None => existing.push(profile),
+        }
+    }
+}
+
 /// Create a new profile with default values
 pub fn create_profile(name: String) -> Profile {
     Profile {
         name,
+        description: String::new(),
+        tags: Vec::new(),
         processes_to_kill: Vec::new(),
         crosshair_image_path: None,
         crosshair_x_offset: 0,
         crosshair_y_offset: 0,
         overlay_enabled: true,
         fan_speed_max: false,
+        default_audio_device: None,
+        power_plan: None,
+        trim_memory: false,
+        process_priority_rules: Vec::new(),
+        deactivate_on_process_exit: None,
+        activation_sound: None,
+        last_activated: None,
+        activation_count: 0,
+        crosshair_kind: CrosshairKind::default(),
+        crosshair_size: default_crosshair_size(),
+        crosshair_thickness: default_crosshair_thickness(),
+        crosshair_color: default_crosshair_color(),
+        crosshair_gap: crosshair_shapes::DEFAULT_GAP,
+        crosshair_refresh_hz: 0,
+        aggressive_topmost: default_aggressive_topmost(),
+        topmost_interval_ms: default_topmost_interval_ms(),
+        topmost_on_foreground: false,
+        hide_crosshair_when_unfocused: false,
+        game_process: None,
+        crosshair_layers: Vec::new(),
+        crosshair_presets: Vec::new(),
+        cycle_crosshair_shortcut: None,
     }
 }
 
@@ -123,6 +750,15 @@ pub fn delete_profile(profiles: &mut Vec<Profile>, index: usize) {
     }
 }
 
+/// `name` followed by `suffix`, with the name cut short so the result stays a valid length
+pub fn name_with_suffix(name: &str, suffix: &str) -> String {
+    let mut end = MAX_NAME_LEN.saturating_sub(suffix.len()).min(name.len());
+    while !name.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", name[..end].trim_end(), suffix)
+}
+
 /// Check if profile name is unique in the list (case-insensitive)
 pub fn is_profile_name_unique(profiles: &[Profile], name: &str, exclude_index: Option<usize>) -> bool {
     let name_lower = name.to_lowercase();
@@ -188,6 +824,329 @@ mod tests {
         assert!(profile.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_image_extension() {
+        let dir = std::env::temp_dir().join(format!("go_image_ext_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut profile = create_profile("Test".to_string());
+
+        for (file, valid) in [("cross.gif", true), ("CROSS.PNG", true), ("cross.jpg", false)] {
+            let path = dir.join(file);
+            fs::write(&path, b"").unwrap();
+            profile.crosshair_image_path = Some(path.to_string_lossy().to_string());
+            assert_eq!(profile.validate().is_ok(), valid, "{}", file);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_validate_crosshair_shape() {
+        let mut profile = create_profile("Test".to_string());
+        assert!(profile.validate().is_ok());
+
+        profile.crosshair_size = 1;
+        assert!(profile.validate().is_err());
+
+        profile.crosshair_size = 10;
+        profile.crosshair_thickness = 11;
+        assert!(profile.validate().is_err());
+
+        profile.crosshair_thickness = 2;
+        profile.crosshair_gap = 10;
+        assert!(profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_crosshair_layers() {
+        let mut profile = create_profile("Test".to_string());
+        let mut layer = CrosshairLayer::new(assets::BUILTIN_DEFAULT_CROSSHAIR.to_string());
+        layer.x_offset = -200;
+        profile.crosshair_layers.push(layer);
+        assert!(profile.validate().is_ok());
+
+        profile.crosshair_layers[0].scale_percent = 5;
+        assert!(profile.validate().is_err());
+
+        profile.crosshair_layers[0].scale_percent = 100;
+        profile.crosshair_layers[0].opacity_percent = 0;
+        assert!(profile.validate().is_err());
+
+        profile.crosshair_layers.push(CrosshairLayer::new("missing.png".to_string()));
+        profile.crosshair_layers[0].opacity_percent = 50;
+        assert!(profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_missing_images() {
+        let mut profile = create_profile("Test".to_string());
+        assert!(profile.missing_images().is_empty());
+
+        profile.crosshair_image_path = Some(assets::BUILTIN_DEFAULT_CROSSHAIR.to_string());
+        profile.crosshair_layers.push(CrosshairLayer::new("moved_away.png".to_string()));
+        assert_eq!(profile.missing_images(), vec!["moved_away.png"]);
+    }
+
+    #[test]
+    fn test_legacy_profile_gets_shape_defaults() {
+        let json = r#"{
+            "name": "Old",
+            "processes_to_kill": [],
+            "crosshair_image_path": null,
+            "crosshair_x_offset": 0,
+            "crosshair_y_offset": 0,
+            "overlay_enabled": true
+        }"#;
+        let profile: Profile = serde_json::from_str(json).unwrap();
+        assert_eq!(profile.crosshair_kind, CrosshairKind::Cross);
+        assert_eq!(profile.crosshair_size, crosshair_shapes::DEFAULT_SIZE);
+        assert_eq!(profile.last_activated, None);
+        assert_eq!(profile.activation_count, 0);
+        assert!(profile.aggressive_topmost);
+        assert_eq!(profile.topmost_interval_ms, 100);
+        assert!(!profile.topmost_on_foreground);
+        assert!(profile.description.is_empty());
+        assert!(profile.crosshair_layers.is_empty());
+        assert!(profile.crosshair_presets.is_empty());
+    }
+
+    #[test]
+    fn test_cycle_crosshair_preset() {
+        let mut profile = create_profile("Test".to_string());
+        assert!(profile.cycle_crosshair_preset().is_none());
+
+        let rifle = profile.current_crosshair("Rifle".to_string());
+        let mut sniper = rifle.clone();
+        sniper.name = "Sniper".to_string();
+        sniper.kind = CrosshairKind::Dot;
+        profile.crosshair_presets = vec![rifle, sniper];
+
+        // The current crosshair matches "Rifle", so the next one is "Sniper", then back around
+        assert_eq!(profile.cycle_crosshair_preset().unwrap().name, "Sniper");
+        assert_eq!(profile.crosshair_kind, CrosshairKind::Dot);
+        assert_eq!(profile.cycle_crosshair_preset().unwrap().name, "Rifle");
+        assert_eq!(profile.crosshair_kind, CrosshairKind::Cross);
+
+        // A crosshair edited away from every preset starts over at the first
+        profile.crosshair_x_offset = 7;
+        assert_eq!(profile.cycle_crosshair_preset().unwrap().name, "Rifle");
+        assert_eq!(profile.crosshair_x_offset, 0);
+
+        profile.cycle_crosshair_shortcut = Some("Ctrl+Alt+C".to_string());
+        assert!(profile.validate().is_ok());
+        profile.cycle_crosshair_shortcut = Some("C".to_string());
+        assert!(profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_copy_settings_from() {
+        let mut source = create_profile("Source".to_string());
+        source.crosshair_kind = CrosshairKind::Circle;
+        source.crosshair_x_offset = 12;
+        source.crosshair_layers.push(CrosshairLayer::new("builtin:default".to_string()));
+        source.processes_to_kill = vec!["discord.exe".to_string()];
+        source.fan_speed_max = true;
+
+        let mut target = create_profile("Target".to_string());
+        target.processes_to_kill = vec!["steam.exe".to_string()];
+        target.activation_count = 4;
+        target.copy_settings_from(&source, CopyFields { crosshair: true, processes: false, fan: true });
+
+        assert_eq!(target.name, "Target");
+        assert_eq!(target.activation_count, 4);
+        assert_eq!(target.crosshair_kind, CrosshairKind::Circle);
+        assert_eq!(target.crosshair_x_offset, 12);
+        assert_eq!(target.crosshair_layers.len(), 1);
+        assert_eq!(target.processes_to_kill, vec!["steam.exe"]);
+        assert!(target.fan_speed_max);
+    }
+
+    #[test]
+    fn test_hide_when_unfocused_needs_game_process() {
+        let mut profile = create_profile("Test".to_string());
+        profile.game_process = Some("cs2.exe".to_string());
+        assert_eq!(profile.crosshair_focus_process(), None);
+
+        profile.hide_crosshair_when_unfocused = true;
+        assert_eq!(profile.crosshair_focus_process().as_deref(), Some("cs2.exe"));
+        assert!(profile.validate().is_ok());
+
+        profile.game_process = None;
+        assert!(profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        assert_eq!(parse_tags(" FPS, Streaming ,,fps, MMO "), vec!["FPS", "Streaming", "MMO"]);
+        assert!(parse_tags(" , ").is_empty());
+
+        let mut profile = create_profile("Test".to_string());
+        profile.tags = parse_tags("FPS");
+        assert!(profile.has_tag("fps"));
+        assert!(!profile.has_tag("MMO"));
+    }
+
+    #[test]
+    fn test_description_summary() {
+        let mut profile = create_profile("Test".to_string());
+        assert_eq!(profile.description_summary(), None);
+
+        profile.description = "\n  Ranked CS2 \nkills launchers too".to_string();
+        assert_eq!(profile.description_summary(), Some("Ranked CS2"));
+    }
+
+    #[test]
+    fn test_format_relative_time() {
+        let now = 1_000_000;
+        assert_eq!(format_relative_time(now - 30, now), "just now");
+        assert_eq!(format_relative_time(now - 5 * 60, now), "5m ago");
+        assert_eq!(format_relative_time(now - 2 * 3600, now), "2h ago");
+        assert_eq!(format_relative_time(now - 3 * 86400, now), "3d ago");
+        // Clock went backwards - don't underflow
+        assert_eq!(format_relative_time(now + 10, now), "just now");
+    }
+
+    #[test]
+    fn test_merge_profiles() {
+        let mut existing = vec![create_profile("CS2".to_string())];
+        let mut incoming = create_profile("cs2".to_string());
+        incoming.fan_speed_max = true;
+
+        // Merge keeps both
+        let mut merged = existing.clone();
+        merge_profiles(&mut merged, vec![incoming.clone(), create_profile("New".to_string())], false);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[1].name, "cs2 (restored)");
+
+        // Replace overwrites the colliding profile
+        merge_profiles(&mut existing, vec![incoming], true);
+        assert_eq!(existing.len(), 1);
+        assert!(existing[0].fan_speed_max);
+
+        // Long names are shortened so the kept copy is still a valid profile
+        let long = "a".repeat(MAX_NAME_LEN);
+        let mut merged = vec![create_profile(long.clone())];
+        merge_profiles(&mut merged, vec![create_profile(long.clone()), create_profile(long)], false);
+        assert!(merged[1].name.ends_with(" (restored)"));
+        assert!(merged[2].name.ends_with(" (restored) 2"));
+        assert!(merged.iter().all(|p| p.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_parse_profiles_reports_broken_profile() {
+        let contents = r#"{
+  "schema_version": 2,
+  "profiles": [
+    { "name": "Ok", "processes_to_kill": [], "crosshair_image_path": null,
+      "crosshair_x_offset": 0, "crosshair_y_offset": 0, "overlay_enabled": true },
+    { "name": "Broken", "processes_to_kill": [], "crosshair_image_path": null,
+      "crosshair_x_offset": "ten", "crosshair_y_offset": 0, "overlay_enabled": true }
+  ]
+}"#;
+        let error = parse_profiles(contents).unwrap_err().to_string();
+        assert!(error.contains("profile #2 ('Broken')"), "{}", error);
+        assert!(error.contains("line 7"), "{}", error);
+
+        let (profiles, version) = parse_profiles(&contents.replace("\"ten\"", "10")).unwrap();
+        assert_eq!(profiles.len(), 2);
+        assert_eq!(version, 2);
+
+        let syntax = parse_profiles("[ { \"name\": } ]").unwrap_err().to_string();
+        assert!(syntax.contains("line 1"), "{}", syntax);
+    }
+
+    #[test]
+    fn test_dedupe_profile_names() {
+        let mut profiles = vec![
+            create_profile("CS2".to_string()),
+            create_profile("cs2".to_string()),
+            create_profile("CS2 (2)".to_string()),
+            create_profile("Valorant".to_string()),
+        ];
+
+        let renamed = dedupe_profile_names(&mut profiles);
+        assert_eq!(renamed, vec![("cs2".to_string(), "cs2 (3)".to_string())]);
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["CS2", "cs2 (3)", "CS2 (2)", "Valorant"]);
+    }
+
+    #[test]
+    fn test_backup_round_trip_skips_invalid_profiles() {
+        let dir = std::env::temp_dir().join(format!("go_backup_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("backup.json");
+
+        let mut with_missing_image = create_profile("C".to_string());
+        with_missing_image.crosshair_image_path = Some("C:\\Gone\\cross.gif".to_string());
+        let profiles = vec![create_profile("A".to_string()), create_profile("B".to_string()), with_missing_image];
+        export_all_profiles(&profiles, &AppConfig::default(), &path).unwrap();
+
+        // Corrupt the second profile
+        let mut value: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        value["profiles"][1]["name"] = serde_json::Value::Null;
+        fs::write(&path, value.to_string()).unwrap();
+
+        let (bundle, skipped) = import_all_profiles(&path).unwrap();
+        assert_eq!(bundle.profiles.len(), 2);
+        assert_eq!(bundle.profiles[0].name, "A");
+        // Kept even though its image isn't on this machine
+        assert_eq!(bundle.profiles[1].name, "C");
+        assert_eq!(skipped, 1);
+        assert!(bundle.config.is_some());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_profiles() {
+        // v1 bare array
+        let (profiles, version) = migrate_profiles(serde_json::json!([])).unwrap();
+        assert_eq!(version, 1);
+        assert!(profiles.is_array());
+
+        // current versioned wrapper
+        let value = serde_json::json!({ "schema_version": PROFILES_SCHEMA_VERSION, "profiles": [] });
+        let (_, version) = migrate_profiles(value).unwrap();
+        assert_eq!(version, PROFILES_SCHEMA_VERSION);
+
+        // v2 image-less profiles keep showing no crosshair; ones with an image or a shape are untouched
+        let value = serde_json::json!({ "schema_version": 2, "profiles": [
+            { "overlay_enabled": true, "crosshair_image_path": null },
+            { "overlay_enabled": true, "crosshair_image_path": "cross.png" },
+            { "overlay_enabled": true, "crosshair_image_path": null, "crosshair_kind": "Dot" }
+        ] });
+        let (profiles, _) = migrate_profiles(value).unwrap();
+        let enabled: Vec<bool> = profiles
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["overlay_enabled"].as_bool().unwrap())
+            .collect();
+        assert_eq!(enabled, vec![false, true, true]);
+
+        // files from a newer build are refused rather than silently mangled
+        let value = serde_json::json!({ "schema_version": PROFILES_SCHEMA_VERSION + 1, "profiles": [] });
+        assert!(migrate_profiles(value).is_err());
+    }
+
+    #[test]
+    fn test_name_with_suffix() {
+        assert_eq!(name_with_suffix("CS2", " (copy)"), "CS2 (copy)");
+
+        let long = "a".repeat(MAX_NAME_LEN);
+        let copy = name_with_suffix(&long, " (copy) 2");
+        assert_eq!(copy.len(), MAX_NAME_LEN);
+        assert!(copy.ends_with(" (copy) 2"));
+
+        // Never splits a multi-byte character
+        let copy = name_with_suffix(&"é".repeat(30), " (copy)");
+        assert!(copy.len() <= MAX_NAME_LEN && copy.ends_with(" (copy)"));
+
+        let mut profile = create_profile(long);
+        profile.name = name_with_suffix(&profile.name, " (copy)");
+        assert!(profile.validate().is_ok());
+    }
+
     #[test]
     fn test_is_profile_name_unique() {
         let profiles = vec![
@@ -0,0 +1,91 @@
+//! Sound cue played when a profile activates
+//!
+//! Uses PlaySoundW with SND_ASYNC, so activation never waits for the sound to finish.
+//! A cue is either a .wav path or one of the Windows system sounds, stored as "system:<alias>".
+
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+const SYSTEM_PREFIX: &str = "system:";
+
+/// Built-in cues as (label, Windows sound alias)
+pub const BUILTIN_CUES: &[(&str, &str)] = &[
+    ("Chime", "SystemAsterisk"),
+    ("Alert", "SystemExclamation"),
+    ("Notify", "SystemNotification"),
+    ("Ding", "SystemDefault"),
+];
+
+/// Stored value for a built-in cue label, e.g. "Chime" -> "system:SystemAsterisk"
+pub fn builtin_cue(label: &str) -> Option<String> {
+    BUILTIN_CUES
+        .iter()
+        .find(|(name, _)| *name == label)
+        .map(|(_, alias)| format!("{}{}", SYSTEM_PREFIX, alias))
+}
+
+/// Label shown in the editor: the built-in cue name, or the .wav file name
+pub fn cue_label(cue: &str) -> String {
+    if let Some(alias) = cue.strip_prefix(SYSTEM_PREFIX) {
+        if let Some((name, _)) = BUILTIN_CUES.iter().find(|(_, a)| *a == alias) {
+            return name.to_string();
+        }
+        return alias.to_string();
+    }
+    cue.rsplit(['\\', '/']).next().unwrap_or(cue).to_string()
+}
+
+/// Check a cue before saving it in a profile
+pub fn validate_cue(cue: &str) -> Result<()> {
+    if cue.starts_with(SYSTEM_PREFIX) {
+        return Ok(());
+    }
+    let path = Path::new(cue);
+    if !path.exists() {
+        return Err(anyhow!("Sound file does not exist: {}", cue));
+    }
+    if !path.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("wav")) {
+        return Err(anyhow!("Sound cue must be a WAV file: {}", cue));
+    }
+    Ok(())
+}
+
+/// Start playing a cue and return immediately
+#[cfg(windows)]
+pub fn play(cue: &str) {
+    use windows::core::PCWSTR;
+    use windows::Win32::Media::Audio::{PlaySoundW, SND_ALIAS, SND_ASYNC, SND_FILENAME, SND_NODEFAULT};
+
+    let (name, kind) = match cue.strip_prefix(SYSTEM_PREFIX) {
+        Some(alias) => (alias, SND_ALIAS),
+        None => (cue, SND_FILENAME),
+    };
+    let wide: Vec<u16> = name.encode_utf16().chain(Some(0)).collect();
+
+    // SND_NODEFAULT: a missing file stays silent instead of playing the error beep
+    let played = unsafe { PlaySoundW(PCWSTR(wide.as_ptr()), None, kind | SND_ASYNC | SND_NODEFAULT) };
+    if !played.as_bool() {
+        println!("[Sound] Failed to play {}", cue);
+    }
+}
+
+#[cfg(not(windows))]
+pub fn play(cue: &str) {
+    println!("[Sound] {}", cue);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cue_labels() {
+        let chime = builtin_cue("Chime").unwrap();
+        assert_eq!(chime, "system:SystemAsterisk");
+        assert_eq!(cue_label(&chime), "Chime");
+        assert_eq!(cue_label("C:\\Sounds\\go.wav"), "go.wav");
+        assert!(builtin_cue("Nope").is_none());
+        assert!(validate_cue(&chime).is_ok());
+        assert!(validate_cue("missing.wav").is_err());
+    }
+}
@@ -76,6 +76,7 @@ impl TrayManager {
         // Create tray icon
         let tray_icon = TrayIconBuilder::new()
             .with_menu(Box::new(menu.clone()))
+            .with_icon(crate::tray_flyout::load_app_icon()?)
             .with_tooltip("Gaming Optimizer - Inactive")
             .build()
             .map_err(|e| anyhow!("Failed to create tray icon: {}", e))?;
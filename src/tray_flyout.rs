@@ -10,40 +10,43 @@ use anyhow::{anyhow, Result};
 use std::sync::mpsc::{Sender, TryRecvError, Receiver, channel};
 use std::time::Instant;
 use tray_icon::{TrayIcon, TrayIconBuilder, TrayIconEvent, MouseButton, MouseButtonState, Icon, menu::MenuEvent};
-use tray_icon::menu::{Menu, MenuItem, MenuId, PredefinedMenuItem};
+use tray_icon::menu::{CheckMenuItem, Menu, MenuItem, MenuId, PredefinedMenuItem, Submenu};
 
-/// Load application icon from favicon.ico file
-fn load_app_icon() -> Result<Icon> {
-    // Try multiple paths
-    let paths_to_try = vec![
-        std::env::current_exe().ok().and_then(|p| p.parent().map(|p| p.join("favicon.ico"))),
-        Some(std::path::PathBuf::from("favicon.ico")),
-        Some(std::path::PathBuf::from("X:\\AI_and_Automation\\Gaming_optimizer\\favicon.ico")),
-    ];
+/// Load application icon
+/// A favicon.ico next to the executable wins (lets users swap the icon); otherwise the
+/// embedded copy is used, so the tray never depends on the working directory.
+pub fn load_app_icon() -> Result<Icon> {
+    let exe_icon = std::env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(|p| p.join("favicon.ico")))
+        .filter(|path| path.exists());
     
-    for path_opt in paths_to_try {
-        if let Some(path) = path_opt {
-            if path.exists() {
-                let icon_data = std::fs::read(&path)
-                    .map_err(|e| anyhow!("Failed to read favicon.ico: {}", e))?;
-                
-                // Decode with image crate
-                let img = image::load_from_memory(&icon_data)
-                    .map_err(|e| anyhow!("Failed to decode icon: {}", e))?;
-                
-                let img = img.resize_exact(16, 16, image::imageops::FilterType::Lanczos3);
-                let rgba = img.to_rgba8();
-                
-                return Icon::from_rgba(rgba.into_raw(), 16, 16)
-                    .map_err(|e| anyhow!("Failed to create icon from image: {:?}", e));
-            }
+    if let Some(path) = exe_icon {
+        match std::fs::read(&path).map_err(|e| anyhow!("{}", e)).and_then(|data| icon_from_bytes(&data)) {
+            Ok(icon) => return Ok(icon),
+            Err(e) => println!("[TRAY] Ignoring {}: {}", path.display(), e),
         }
     }
     
-    // Fallback: green square
-    let icon_rgba: Vec<u8> = (0..16*16).flat_map(|_| vec![0x00, 0xAA, 0x00, 0xFF]).collect();
-    Icon::from_rgba(icon_rgba, 16, 16)
-        .map_err(|e| anyhow!("Failed to create fallback icon: {:?}", e))
+    icon_from_bytes(crate::assets::APP_ICON).or_else(|e| {
+        println!("[TRAY] Embedded icon failed to decode: {}", e);
+        // Last resort: green square
+        let icon_rgba: Vec<u8> = (0..16*16).flat_map(|_| vec![0x00, 0xAA, 0x00, 0xFF]).collect();
+        Icon::from_rgba(icon_rgba, 16, 16)
+            .map_err(|e| anyhow!("Failed to create fallback icon: {:?}", e))
+    })
+}
+
+/// Decode icon file bytes into a 16x16 tray icon
+fn icon_from_bytes(icon_data: &[u8]) -> Result<Icon> {
+    let img = image::load_from_memory(icon_data)
+        .map_err(|e| anyhow!("Failed to decode icon: {}", e))?;
+    
+    let img = img.resize_exact(16, 16, image::imageops::FilterType::Lanczos3);
+    let rgba = img.to_rgba8();
+    
+    Icon::from_rgba(rgba.into_raw(), 16, 16)
+        .map_err(|e| anyhow!("Failed to create icon from image: {:?}", e))
 }
 
 /// Create a TrayToGui sender that forwards profile activations to a String channel
@@ -68,7 +71,12 @@ pub struct TrayFlyoutManager {
     flyout: Option<FlyoutWindow>,
     profiles: Vec<Profile>,
     active_profile: Option<String>,
+    /// "Profiles" submenu, rebuilt whenever the profile list changes
+    profiles_menu: Submenu,
+    /// One check item per profile, in the same order as `profiles`
+    profile_items: Vec<CheckMenuItem>,
     pub menu_item_settings: MenuId,
+    pub menu_item_toggle_crosshair: MenuId,
     pub menu_item_docs: MenuId,
     pub menu_item_bug_report: MenuId,
     pub menu_item_exit: MenuId,
@@ -99,14 +107,22 @@ impl TrayFlyoutManager {
         
         // Create context menu (appears on right-click)
         let menu = Menu::new();
+        let profiles_menu = Submenu::new("Profiles", true);
         let settings_item = MenuItem::new("Open Settings", true, None);
+        let crosshair_item = MenuItem::new("Toggle Crosshair", true, None);
         let docs_item = MenuItem::new("Documentation", true, None);
         let bug_item = MenuItem::new("Report Bug", true, None);
         let separator = PredefinedMenuItem::separator();
         let exit_item = MenuItem::new("Exit", true, None);
         
+        menu.append(&profiles_menu)
+            .map_err(|e| anyhow!("Failed to add profiles submenu: {}", e))?;
+        menu.append(&PredefinedMenuItem::separator())
+            .map_err(|e| anyhow!("Failed to add separator: {}", e))?;
         menu.append(&settings_item)
             .map_err(|e| anyhow!("Failed to add settings item: {}", e))?;
+        menu.append(&crosshair_item)
+            .map_err(|e| anyhow!("Failed to add crosshair item: {}", e))?;
         menu.append(&docs_item)
             .map_err(|e| anyhow!("Failed to add docs item: {}", e))?;
         menu.append(&bug_item)
@@ -118,6 +134,7 @@ impl TrayFlyoutManager {
         
         // Store menu IDs for event handling
         let menu_item_settings = settings_item.id().clone();
+        let menu_item_toggle_crosshair = crosshair_item.id().clone();
         let menu_item_docs = docs_item.id().clone();
         let menu_item_bug_report = bug_item.id().clone();
         let menu_item_exit = exit_item.id().clone();
@@ -157,12 +174,15 @@ impl TrayFlyoutManager {
             }
         }));
 
-        let manager = TrayFlyoutManager {
+        let mut manager = TrayFlyoutManager {
             tray_icon,
             flyout: None,
             profiles,
             active_profile,
+            profiles_menu,
+            profile_items: Vec::new(),
             menu_item_settings,
+            menu_item_toggle_crosshair,
             menu_item_docs,
             menu_item_bug_report,
             menu_item_exit,
@@ -170,6 +190,7 @@ impl TrayFlyoutManager {
             last_click_time: None,
             pending_single_click: false,
         };
+        manager.rebuild_profiles_menu();
 
         Ok((manager, event_rx, menu_rx, profile_rx))
     }
@@ -217,11 +238,14 @@ impl TrayFlyoutManager {
 
         // Create and show flyout
         println!("[FLYOUT] Creating flyout window with {} profiles", self.profiles.len());
+        let config = crate::config::load_config();
         let flyout = FlyoutWindow::new(
             _tray_rect,
             self.profiles.clone(),
             self.active_profile.clone(),
             ipc_sender,
+            config.flyout_backdrop,
+            config.flyout_style.clamped(),
         )?;
 
         println!("[FLYOUT] Showing flyout window");
@@ -253,9 +277,47 @@ impl TrayFlyoutManager {
         self.tray_icon.set_tooltip(Some(&tooltip));
     }
 
+    /// Replace the Profiles submenu entries with one check item per profile
+    fn rebuild_profiles_menu(&mut self) {
+        while self.profiles_menu.remove_at(0).is_some() {}
+        self.profile_items.clear();
+        
+        if self.profiles.is_empty() {
+            let _ = self.profiles_menu.append(&MenuItem::new("No profiles yet", false, None));
+            return;
+        }
+        
+        for profile in &self.profiles {
+            let checked = self.active_profile.as_ref() == Some(&profile.name);
+            let item = CheckMenuItem::new(&profile.name, true, checked, None);
+            if let Err(e) = self.profiles_menu.append(&item) {
+                println!("[TRAY] Failed to add profile menu item: {}", e);
+            }
+            self.profile_items.push(item);
+        }
+    }
+    
+    /// Put the check mark on the active profile only
+    /// Also undoes Windows toggling an item on click when the activation didn't happen
+    fn sync_profile_checks(&self) {
+        for (item, profile) in self.profile_items.iter().zip(&self.profiles) {
+            item.set_checked(self.active_profile.as_ref() == Some(&profile.name));
+        }
+    }
+    
+    /// Profile behind a Profiles submenu item, if the menu event came from one
+    pub fn profile_for_menu_id(&self, id: &MenuId) -> Option<String> {
+        self.profile_items
+            .iter()
+            .zip(&self.profiles)
+            .find(|(item, _)| item.id() == id)
+            .map(|(_, profile)| profile.name.clone())
+    }
+
     /// Update profiles list
     pub fn update_profiles(&mut self, profiles: Vec<Profile>) {
         self.profiles = profiles;
+        self.rebuild_profiles_menu();
         if let Some(ref mut flyout) = self.flyout {
             let _ = flyout.update_profiles(self.profiles.clone(), self.active_profile.clone());
         }
@@ -265,6 +327,7 @@ impl TrayFlyoutManager {
     pub fn set_active_profile(&mut self, active: Option<String>) {
         self.active_profile = active;
         self.update_tooltip();
+        self.sync_profile_checks();
         if let Some(ref mut flyout) = self.flyout {
             let _ = flyout.update_profiles(self.profiles.clone(), self.active_profile.clone());
         }
@@ -389,6 +452,9 @@ pub fn run_tray_flyout_thread(
                     if event.id == tray.menu_item_settings {
                         println!("[MENU] Open Settings clicked");
                         let _ = channels.to_gui.send(crate::ipc::TrayToGui::OpenSettings);
+                    } else if event.id == tray.menu_item_toggle_crosshair {
+                        println!("[MENU] Toggle Crosshair clicked");
+                        let _ = channels.to_gui.send(crate::ipc::TrayToGui::ToggleCrosshair);
                     } else if event.id == tray.menu_item_docs {
                         println!("[MENU] Documentation clicked");
                         // Open documentation URL
@@ -405,6 +471,11 @@ pub fn run_tray_flyout_thread(
                         println!("[MENU] Exit clicked");
                         let _ = channels.to_gui.send(crate::ipc::TrayToGui::Exit);
                         break;
+                    } else if let Some(name) = tray.profile_for_menu_id(&event.id) {
+                        println!("[MENU] Profile '{}' clicked", name);
+                        // The check mark moves once the GUI reports the new active profile
+                        tray.sync_profile_checks();
+                        let _ = channels.to_gui.send(crate::ipc::TrayToGui::ActivateProfile(name));
                     }
                 }
                 Err(_) => {}